@@ -2,16 +2,45 @@ use anyhow::bail;
 use cmake::Config;
 use fs2::FileExt;
 use std::fs::File;
-use std::io::ErrorKind;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+#[cfg(unix)]
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::thread::available_parallelism;
-use std::time::Duration;
-use std::{env, fs};
+#[cfg(unix)]
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io};
 
-const TON_MONOREPO_URL: &str = "https://github.com/ton-blockchain/ton";
-const TON_MONOREPO_REVISION: &str = "v2026.05";
+const DEFAULT_TON_MONOREPO_URL: &str = "https://github.com/ton-blockchain/ton";
+const DEFAULT_TON_MONOREPO_REVISION: &str = "v2026.05";
+const TON_MONOREPO_URL_ENV: &str = "TON_MONOREPO_URL";
+const TON_MONOREPO_REVISION_ENV: &str = "TON_MONOREPO_REVISION";
 const TON_MONOREPO_DIR_ENV: &str = "TON_MONOREPO_DIR";
+// directory with `.patch` files applied to the checkout
+const TON_MONOREPO_PATCHES_ENV: &str = "TON_MONOREPO_PATCHES";
+// copies of applied patches, to revert them once removed from TON_MONOREPO_PATCHES
+const APPLIED_PATCHES_DIR: &str = ".git/tonlib-sys-patches";
+// C compiler used for TON only, takes precedence over CC
+const TONLIB_SYS_CC_ENV: &str = "TONLIB_SYS_CC";
+const TONLIB_SYS_CXX_ENV: &str = "TONLIB_SYS_CXX";
+// sysroot of the cargo target for cross builds
+const TONLIB_SYS_SYSROOT_ENV: &str = "TONLIB_SYS_SYSROOT";
+// prefix with `include/` and `lib/` of OpenSSL, libsodium, secp256k1 and zlib built by emscripten
+const TONLIB_SYS_WASM_DEPS_ENV: &str = "TONLIB_SYS_WASM_DEPS";
+// `-march` for TON, takes precedence over `TARGET_CPU_MARCH` and rustc `-C target-cpu`
+const TONLIB_SYS_MARCH_ENV: &str = "TONLIB_SYS_MARCH";
+// AVX-512 subsets and VPCLMULQDQ, which is used together with them
+const NO_AVX512_FLAGS: &str = "-mno-avx512f -mno-avx512dq -mno-avx512cd -mno-avx512bw -mno-avx512vl -mno-avx512ifma -mno-avx512vbmi -mno-vpclmulqdq";
+// lines of the build log shown when the build fails
+const FAILURE_LOG_LINES: usize = 30;
+// probed in this order when no compiler is given
+const CLANG_VERSIONS: &[u32] = &[21, 20, 19, 18, 17, 16];
+// keep in sync with src/bin/tonlib-sys-cache.rs
+const BUILD_KEY_FILE: &str = ".tonlib-sys-build-key";
+const BUILD_LAST_USED_FILE: &str = ".tonlib-sys-last-used";
 
 #[cfg(feature = "with_debug_info")]
 const CMAKE_BUILD_TYPE: &str = "RelWithDebInfo";
@@ -19,15 +48,94 @@ const CMAKE_BUILD_TYPE: &str = "RelWithDebInfo";
 const CMAKE_BUILD_TYPE: &str = "Release";
 
 fn main() {
+    let source = TonSource::from_env();
+    println!(
+        "cargo:rustc-env=TON_MONOREPO_DIR_NAME={}",
+        source.dir_name()
+    );
+
     #[cfg(feature = "shared-tonlib")]
     println!("cargo:rustc-link-lib=tonlibjson");
 
     #[cfg(not(feature = "shared-tonlib"))]
-    build_monorepo();
+    build_monorepo(&source);
+}
+
+/// Where TON sources come from: `TON_MONOREPO_URL`/`TON_MONOREPO_REVISION` override the defaults,
+/// `TON_MONOREPO_PATCHES` adds local patches.
+struct TonSource {
+    url: String,
+    revision: String,
+    /// `.patch` files sorted by name.
+    patches: Vec<PathBuf>,
+}
+
+impl TonSource {
+    fn from_env() -> Self {
+        for name in [
+            TON_MONOREPO_URL_ENV,
+            TON_MONOREPO_REVISION_ENV,
+            TON_MONOREPO_PATCHES_ENV,
+        ] {
+            println!("cargo:rerun-if-env-changed={name}");
+        }
+        let non_empty = |name: &str| env::var(name).ok().filter(|value| !value.trim().is_empty());
+        let mut patches = vec![];
+        if let Some(patches_dir) = env::var_os(TON_MONOREPO_PATCHES_ENV) {
+            let patches_dir = PathBuf::from(patches_dir);
+            println!("cargo:rerun-if-changed={}", patches_dir.display());
+            let entries = fs::read_dir(&patches_dir).unwrap_or_else(|error| {
+                panic!("Failed to read {}: {error}", patches_dir.display())
+            });
+            patches = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|extension| extension == "patch")
+                })
+                .collect();
+            patches.sort();
+            for patch in &patches {
+                println!("cargo:rerun-if-changed={}", patch.display());
+            }
+        }
+        Self {
+            url: non_empty(TON_MONOREPO_URL_ENV)
+                .unwrap_or_else(|| DEFAULT_TON_MONOREPO_URL.to_owned()),
+            revision: non_empty(TON_MONOREPO_REVISION_ENV)
+                .unwrap_or_else(|| DEFAULT_TON_MONOREPO_REVISION.to_owned()),
+            patches,
+        }
+    }
+
+    /// Checkout directory name, `tonlibsys_ton_<revision>` for the default repository
+    /// and `tonlibsys_ton_<revision>_<url hash>` for forks.
+    fn dir_name(&self) -> String {
+        let revision: String = self
+            .revision
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '-') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        if self.url == DEFAULT_TON_MONOREPO_URL {
+            format!("tonlibsys_ton_{revision}")
+        } else {
+            format!(
+                "tonlibsys_ton_{revision}_{:08x}",
+                fnv1a(self.url.as_bytes()) as u32
+            )
+        }
+    }
 }
 
-fn build_monorepo() {
-    let monorepo_dir = resolve_monorepo_dir();
+fn build_monorepo(source: &TonSource) {
+    let monorepo_dir = resolve_monorepo_dir(source);
     println!("Using {} folder for TON monorepo", monorepo_dir.display());
     if let Some(parent_dir) = monorepo_dir.parent() {
         fs::create_dir_all(parent_dir)
@@ -35,63 +143,127 @@ fn build_monorepo() {
     }
     let _repo_lock = repo_lock(&monorepo_dir);
 
-    #[cfg(feature = "no_avx512")]
-    disable_avx512_for_rustc();
-    env::set_var("TON_MONOREPO_REVISION", TON_MONOREPO_REVISION);
-    println!("cargo:rerun-if-env-changed=TON_MONOREPO_REVISION");
     println!("cargo:rerun-if-env-changed={TON_MONOREPO_DIR_ENV}");
     println!("cargo:rerun-if-changed=build.rs");
-    checkout_repo(&monorepo_dir).unwrap();
-    patch_macos_dsymutil_linker_hook(&monorepo_dir);
+    let started = Instant::now();
+    checkout_repo(&monorepo_dir, source).unwrap_or_else(|error| {
+        panic!(
+            "Failed to check out TON {} from {} into {}: {error:#}\n\
+             Check network access, or point {TON_MONOREPO_DIR_ENV} to an existing checkout",
+            source.revision,
+            source.url,
+            monorepo_dir.display()
+        )
+    });
+    println!(
+        "cargo:warning=tonlib-sys: TON {} checked out in {:.1?}",
+        source.revision,
+        started.elapsed()
+    );
+
+    let cargo_target = CargoTarget::from_env();
+    for patch in BUILTIN_PATCHES {
+        if patch.target_os.is_none_or(|os| os == cargo_target.os) {
+            apply_builtin_patch(&monorepo_dir, patch);
+        }
+    }
+    apply_patches(&monorepo_dir, &source.patches)
+        .unwrap_or_else(|error| panic!("Failed to patch TON sources: {error:#}"));
+
+    let toolchain = select_toolchain(&cargo_target);
+    println!(
+        "Using {} / {} ({}) to build TON for {}",
+        toolchain.cc, toolchain.cxx, toolchain.source, cargo_target.triple
+    );
 
     #[cfg(target_os = "macos")]
     install_macos_deps();
 
-    if cfg!(target_os = "macos") {
+    if no_avx512(&cargo_target) {
+        warn_rustc_avx512();
+    }
+
+    // sanitizer runtimes are linked by the compiler driver used as linker
+    for flag in sanitizer_flags().split_whitespace() {
+        println!("cargo:rustc-link-arg={flag}");
+    }
+    if let Some(lto) = lto_config(&cargo_target, &toolchain) {
+        check_lto_link(&cargo_target, &lto);
+    }
+
+    if cargo_target.os == "macos" {
         println!("cargo:rustc-link-lib=dylib=c++");
         println!("cargo:rustc-link-arg=-lc++");
     }
-    if cfg!(target_os = "linux") {
-        println!("cargo:rustc-link-lib=dylib=stdc++");
-        println!("cargo:rustc-link-arg=-lstdc++");
-        println!("cargo:rustc-env=CC=clang");
-        println!("cargo:rustc-env=CXX=clang++");
+    if cargo_target.os == "linux" {
+        if cfg!(feature = "portable") {
+            link_static_libstdcxx(&toolchain);
+        } else {
+            println!("cargo:rustc-link-lib=dylib=stdc++");
+            println!("cargo:rustc-link-arg=-lstdc++");
+        }
         println!("cargo:rustc-env=CMAKE_CXX_STANDARD=20");
     }
 
-    env::set_var("LD_LIBRARY_PATH", "lib/x86_64-linux-gnu");
-
-    let build_dir = run_build("tonlibjson", &monorepo_dir);
-    // === ORDER DOES MATTER!!! ===
-    // === tonlibjson libraries ===
-    // tonlib
-    println!("cargo:rustc-link-search=native={build_dir}/build/tonlib");
-    println!("cargo:rustc-link-lib=static=tonlibjson");
-    println!("cargo:rustc-link-lib=static=tonlib");
-    // lite-client
-    println!("cargo:rustc-link-search=native={build_dir}/build/lite-client");
-    println!("cargo:rustc-link-lib=static=lite-client-common");
-    // tdactor
-    println!("cargo:rustc-link-search=native={build_dir}/build/tdactor");
-    println!("cargo:rustc-link-lib=static=tdactor");
-    // tl
-    println!("cargo:rustc-link-search=native={build_dir}/build/tl");
-    println!("cargo:rustc-link-lib=static=tl_tonlib_api_json");
-    println!("cargo:rustc-link-lib=static=tl_tonlib_api");
-    println!("cargo:rustc-link-lib=static=tl_lite_api");
-    println!("cargo:rustc-link-lib=static=tl_api");
-    // adnl
-    println!("cargo:rustc-link-search=native={build_dir}/build/adnl");
-    println!("cargo:rustc-link-lib=static=adnllite");
-    // tl-utils
-    println!("cargo:rustc-link-search=native={build_dir}/build/tl-utils");
-    println!("cargo:rustc-link-lib=static=tl-utils");
-    println!("cargo:rustc-link-lib=static=tl-lite-utils");
-    // keys
-    println!("cargo:rustc-link-search=native={build_dir}/build/keys");
-    println!("cargo:rustc-link-lib=static=keys");
-
-    let build_dir = run_build("emulator", &monorepo_dir);
+    if cargo_target.is_emscripten() {
+        println!(
+            "cargo:rustc-link-search=native={}",
+            wasm_deps_dir().join("lib").display()
+        );
+    } else if cargo_target.is_cross() {
+        for dir in cross_library_dirs(&cargo_target) {
+            println!("cargo:rustc-link-search=native={}", dir.display());
+        }
+    } else if cfg!(target_os = "linux") {
+        env::set_var(
+            "LD_LIBRARY_PATH",
+            format!("lib/{}", cargo_target.gnu_triple()),
+        );
+    }
+
+    // tonlib needs sockets, only the emulator is built for the browser
+    if !cargo_target.is_emscripten() {
+        let build_dir = run_build(
+            "tonlibjson",
+            &monorepo_dir,
+            source,
+            &cargo_target,
+            &toolchain,
+        );
+        // === ORDER DOES MATTER!!! ===
+        // === tonlibjson libraries ===
+        // tonlib
+        println!("cargo:rustc-link-search=native={build_dir}/build/tonlib");
+        println!("cargo:rustc-link-lib=static=tonlibjson");
+        println!("cargo:rustc-link-lib=static=tonlib");
+        // lite-client
+        println!("cargo:rustc-link-search=native={build_dir}/build/lite-client");
+        println!("cargo:rustc-link-lib=static=lite-client-common");
+        // tdactor
+        println!("cargo:rustc-link-search=native={build_dir}/build/tdactor");
+        println!("cargo:rustc-link-lib=static=tdactor");
+        // tl
+        println!("cargo:rustc-link-search=native={build_dir}/build/tl");
+        println!("cargo:rustc-link-lib=static=tl_tonlib_api_json");
+        println!("cargo:rustc-link-lib=static=tl_tonlib_api");
+        println!("cargo:rustc-link-lib=static=tl_lite_api");
+        println!("cargo:rustc-link-lib=static=tl_api");
+        // adnl
+        println!("cargo:rustc-link-search=native={build_dir}/build/adnl");
+        println!("cargo:rustc-link-lib=static=adnllite");
+        // tl-utils
+        println!("cargo:rustc-link-search=native={build_dir}/build/tl-utils");
+        println!("cargo:rustc-link-lib=static=tl-utils");
+        println!("cargo:rustc-link-lib=static=tl-lite-utils");
+        // keys
+        println!("cargo:rustc-link-search=native={build_dir}/build/keys");
+        println!("cargo:rustc-link-lib=static=keys");
+    }
+
+    let build_dir = run_build("emulator", &monorepo_dir, source, &cargo_target, &toolchain);
+    if no_avx512(&cargo_target) {
+        verify_no_avx512(Path::new(&build_dir), &cargo_target, &toolchain);
+    }
     // === emulator libraries ===
     // emulator
     println!("cargo:rustc-link-search=native={build_dir}/build/emulator");
@@ -109,77 +281,361 @@ fn build_monorepo() {
     // third-party
     println!("cargo:rustc-link-search=native={build_dir}/build/third-party/blst");
     println!("cargo:rustc-link-lib=static=blst");
+    // system libs are linked statically into the wasm module and into portable binaries
+    let static_deps = cargo_target.is_emscripten() || cfg!(feature = "portable");
     // openssl
-    if cfg!(target_os = "linux") {
+    if cargo_target.is_emscripten() {
+        println!("cargo:rustc-link-lib=static=crypto");
+    } else if cargo_target.os == "linux" && !cargo_target.is_cross() {
         // TON builds its own OpenSSL. Use that on Linux to avoid symbol/version mismatches
-        // with runner-provided system libcrypto.
+        // with runner-provided system libcrypto. Cross builds use libcrypto of the sysroot.
         println!("cargo:rustc-link-search=native={build_dir}/build/third-party/openssl/lib");
         println!("cargo:rustc-link-lib=static=crypto");
+    } else if static_deps {
+        link_search_static("crypto", &build_dir, &cargo_target);
+        println!("cargo:rustc-link-lib=static=crypto");
     } else {
         println!("cargo:rustc-link-lib=crypto");
     }
-    // dynamic libs
-    println!("cargo:rustc-link-lib=dylib=z"); // zlib
-    println!("cargo:rustc-link-lib=dylib=sodium");
-    println!("cargo:rustc-link-lib=dylib=secp256k1");
+    // zlib, libsodium, secp256k1
+    for lib in ["z", "sodium", "secp256k1"] {
+        // zlib is part of macOS itself, it has no static version there
+        if !static_deps || (cargo_target.os == "macos" && lib == "z") {
+            println!("cargo:rustc-link-lib=dylib={lib}");
+            continue;
+        }
+        if !cargo_target.is_emscripten() {
+            link_search_static(lib, &build_dir, &cargo_target);
+        }
+        println!("cargo:rustc-link-lib=static={lib}");
+    }
     println!("cargo:rustc-link-search=native={build_dir}/build/third-party/crc32c");
     println!("cargo:rustc-link-lib=static=crc32c");
 }
 
-fn run_build(target: &str, monorepo_dir: &Path) -> String {
+fn run_build(
+    target: &str,
+    monorepo_dir: &Path,
+    source: &TonSource,
+    cargo_target: &CargoTarget,
+    toolchain: &Toolchain,
+) -> String {
     println!("\nBuilding target: {target}...");
 
     let mut cxx_flags = "-w";
-    if cfg!(target_os = "linux") {
+    if cargo_target.os == "linux" {
         cxx_flags = "-w -std=c++20 --include=algorithm";
     }
-    let use_emscripten = env::var("CARGO_CFG_TARGET_ARCH")
-        .map(|arch| arch == "wasm32")
-        .unwrap_or(false);
+    let sanitizer_flags = sanitizer_flags();
+    let lto = lto_config(cargo_target, toolchain);
+    let link_flags = [
+        sanitizer_flags.as_str(),
+        lto.as_ref().map_or("", |lto| lto.flag),
+    ]
+    .into_iter()
+    .filter(|flags| !flags.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+    let no_avx512_flags = if no_avx512(cargo_target) {
+        NO_AVX512_FLAGS
+    } else {
+        ""
+    };
+    let target_cpu = target_cpu(cargo_target);
+    let mcpu_flag = match &target_cpu {
+        Some(TargetCpu::Mcpu(cpu)) => format!("-mcpu={cpu}"),
+        _ => String::new(),
+    };
+    let extra_flags = [link_flags.as_str(), no_avx512_flags, mcpu_flag.as_str()]
+        .into_iter()
+        .filter(|flags| !flags.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let c_flags = format!("-w {extra_flags}").trim_end().to_owned();
+    let cxx_flags = format!("{cxx_flags} {extra_flags}").trim_end().to_owned();
+    let use_emscripten = cargo_target.is_emscripten();
+    // TON defaults to `-march=native`, which is wrong for another target or next to `-mcpu`
+    let ton_arch = match target_cpu {
+        Some(TargetCpu::March(march)) => Some(march),
+        Some(TargetCpu::Mcpu(_)) => Some(String::new()),
+        None => cargo_target.is_cross().then(String::new),
+    };
+
+    env::set_var("CC", &toolchain.cc);
+    env::set_var("CXX", &toolchain.cxx);
+    let mut defines: Vec<(&str, &str)> = vec![
+        ("CMAKE_C_COMPILER", &toolchain.cc),
+        ("CMAKE_CXX_COMPILER", &toolchain.cxx),
+        (
+            "USE_EMSCRIPTEN",
+            if use_emscripten { "true" } else { "false" },
+        ),
+        ("TONLIBJSON_STATIC", "ON"),
+        ("EMULATOR_STATIC", "ON"),
+        ("TON_ONLY_TONLIB", "ON"),
+        // statically link system libraries such as libstdc++
+        (
+            "PORTABLE",
+            if cfg!(feature = "portable") {
+                "ON"
+            } else {
+                "OFF"
+            },
+        ),
+        ("CMAKE_BUILD_TYPE", CMAKE_BUILD_TYPE),
+        ("CMAKE_C_FLAGS", &c_flags),
+        ("CMAKE_CXX_FLAGS", &cxx_flags),
+    ];
+    if !link_flags.is_empty() {
+        defines.push(("CMAKE_EXE_LINKER_FLAGS", &link_flags));
+        defines.push(("CMAKE_SHARED_LINKER_FLAGS", &link_flags));
+    }
+    if let Some(ton_arch) = &ton_arch {
+        defines.push(("TON_ARCH", ton_arch));
+    }
+    if let Some(lto) = &lto {
+        if let Some((ar, ranlib)) = &lto.archiver {
+            defines.push(("CMAKE_AR", ar));
+            defines.push(("CMAKE_RANLIB", ranlib));
+        }
+    }
+    let wasm_deps = if use_emscripten {
+        wasm_dep_defines()
+    } else {
+        vec![]
+    };
+    defines.extend(
+        wasm_deps
+            .iter()
+            .map(|(name, value)| (*name, value.as_str())),
+    );
 
     let mut cfg = Config::new(monorepo_dir);
-    if cfg!(target_os = "linux") {
-        env::set_var("CC", "clang-21");
-        env::set_var("CXX", "clang++-21");
-        cfg.define("CMAKE_C_COMPILER", "clang-21")
-            .define("CMAKE_CXX_COMPILER", "clang++-21");
+    for (name, value) in &defines {
+        cfg.define(name, value);
     }
 
-    let shared_build_dir = resolve_shared_build_dir(monorepo_dir);
+    let toolchain_file = cross_toolchain_file(cargo_target);
+    let shared_build_dir = resolve_shared_build_dir(
+        monorepo_dir,
+        source,
+        cargo_target,
+        toolchain,
+        &defines,
+        toolchain_file.as_deref(),
+    );
+    if let Some(toolchain_file) = &toolchain_file {
+        let path = shared_build_dir.join("tonlib-sys-toolchain.cmake");
+        fs::write(&path, toolchain_file)
+            .unwrap_or_else(|error| panic!("Failed to write {}: {error}", path.display()));
+        cfg.define("CMAKE_TOOLCHAIN_FILE", path);
+    }
     let dst = cfg
         .out_dir(&shared_build_dir)
-        .define(
-            "USE_EMSCRIPTEN",
-            if use_emscripten { "true" } else { "false" },
-        )
-        .define("TONLIBJSON_STATIC", "ON")
-        .define("EMULATOR_STATIC", "ON")
-        .define("TON_ONLY_TONLIB", "ON")
-        // .define("PORTABLE", "true") // statically link system libraries such as libstdc++
-        .define("CMAKE_BUILD_TYPE", CMAKE_BUILD_TYPE)
-        .define("CMAKE_C_FLAGS", "-w")
-        .define("CMAKE_CXX_FLAGS", cxx_flags)
         .build_arg("-j")
         .build_arg(available_parallelism().unwrap().get().to_string())
         .configure_arg("-Wno-dev")
         .build_target(target)
-        .always_configure(true)
-        .very_verbose(true);
+        .always_configure(true);
+    if !no_avx512_flags.is_empty() {
+        dst.asmflag(no_avx512_flags);
+    }
 
-    #[cfg(all(feature = "no_avx512", not(target_os = "macos")))]
-    disable_avx512_for_gcc(dst);
+    // full CMake and compiler output goes to the log, cargo only gets a summary
+    let log_path = shared_build_dir.join(format!("tonlib-sys-{target}.log"));
+    let started = Instant::now();
+    let log_tee = LogTee::start(&log_path)
+        .inspect_err(|error| {
+            println!("cargo:warning=tonlib-sys: build log is not written: {error}")
+        })
+        .ok();
+    let logged = log_tee.is_some();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| dst.build()));
+    drop(log_tee);
+    match result {
+        Ok(dst) if logged => {
+            println!(
+                "cargo:warning=tonlib-sys: built {target} in {:.1?}, log: {}",
+                started.elapsed(),
+                log_path.display()
+            );
+            dst.display().to_string()
+        }
+        Ok(dst) => {
+            println!(
+                "cargo:warning=tonlib-sys: built {target} in {:.1?}",
+                started.elapsed()
+            );
+            dst.display().to_string()
+        }
+        Err(_) if logged => panic!("{}", build_failure(target, started, &log_path)),
+        Err(_) => panic!(
+            "Failed to build TON target {target} after {:.1?}, see the output above",
+            started.elapsed()
+        ),
+    }
+}
+
+/// Redirects stdout and stderr, including output of CMake and compilers started by the cmake
+/// crate, into a log file. Only `cargo:` directives are passed on to cargo.
+#[cfg(unix)]
+struct LogTee {
+    saved_stdout: OwnedFd,
+    saved_stderr: OwnedFd,
+    thread: Option<JoinHandle<()>>,
+}
+
+#[cfg(unix)]
+impl LogTee {
+    fn start(log_path: &Path) -> io::Result<Self> {
+        let mut log = File::create(log_path)?;
+        let mut fds = [0; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let (read_end, write_end) =
+            unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) };
+        io::stdout().flush()?;
+        let saved_stdout = owned_fd(unsafe { libc::dup(1) })?;
+        let saved_stderr = owned_fd(unsafe { libc::dup(2) })?;
+        let mut cargo = File::from(saved_stdout.try_clone()?);
+        redirect(&write_end, 1)?;
+        if let Err(error) = redirect(&write_end, 2) {
+            let _ = redirect(&saved_stdout, 1);
+            return Err(error);
+        }
+        drop(write_end);
+        let pipe = BufReader::new(File::from(read_end));
+        // ends once stdout and stderr are restored, closing the pipe
+        let thread = std::thread::spawn(move || {
+            for line in pipe.split(b'\n').map_while(Result::ok) {
+                let _ = log.write_all(&line).and_then(|_| log.write_all(b"\n"));
+                // i.e. `rerun-if-env-changed` printed by the cmake crate
+                if line.starts_with(b"cargo:") {
+                    let _ = cargo.write_all(&line).and_then(|_| cargo.write_all(b"\n"));
+                }
+            }
+        });
+        Ok(Self {
+            saved_stdout,
+            saved_stderr,
+            thread: Some(thread),
+        })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for LogTee {
+    fn drop(&mut self) {
+        let _ = io::stdout().flush();
+        let _ = redirect(&self.saved_stdout, 1);
+        let _ = redirect(&self.saved_stderr, 2);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Takes ownership of a descriptor returned by libc, `-1` becomes the OS error.
+#[cfg(unix)]
+fn owned_fd(fd: i32) -> io::Result<OwnedFd> {
+    if fd == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
 
-    dst.build().display().to_string()
+/// Points descriptor `target` (1 - stdout, 2 - stderr) to `fd`.
+#[cfg(unix)]
+fn redirect(fd: &OwnedFd, target: i32) -> io::Result<()> {
+    if unsafe { libc::dup2(fd.as_raw_fd(), target) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Output can't be redirected here, the build prints to cargo as before.
+#[cfg(not(unix))]
+struct LogTee;
+
+#[cfg(not(unix))]
+impl LogTee {
+    fn start(_log_path: &Path) -> io::Result<Self> {
+        Err(io::Error::new(
+            ErrorKind::Unsupported,
+            "output redirection requires a Unix host",
+        ))
+    }
+}
+
+/// Failure patterns in CMake and compiler output, lowercase, with what to do about them.
+const KNOWN_FAILURES: &[(&[&str], &str)] = &[
+    (
+        &[
+            "is not a full path and was not found in the path",
+            "no cmake_c_compiler could be found",
+            "no cmake_cxx_compiler could be found",
+        ],
+        "C/C++ compiler not found. Install clang (i.e. `apt install clang-21`) or set TONLIB_SYS_CC/TONLIB_SYS_CXX",
+    ),
+    (
+        &["is `cmake` not installed"],
+        "cmake not found. Install it: `apt install cmake` or `brew install cmake`",
+    ),
+    (
+        &["could not find sodium", "sodium.h: no such file", "cannot find -lsodium"],
+        "libsodium not found. Install it: `apt install libsodium-dev` or `brew install libsodium`",
+    ),
+    (
+        &["could not find lz4", "lz4.h: no such file", "cannot find -llz4"],
+        "lz4 not found. Install it: `apt install liblz4-dev` or `brew install lz4`",
+    ),
+    (
+        &["could not find secp256k1", "secp256k1.h: no such file", "cannot find -lsecp256k1"],
+        "secp256k1 not found. Install it: `apt install libsecp256k1-dev` or `brew install secp256k1`",
+    ),
+    (
+        &["could not find zlib", "zlib.h: no such file", "cannot find -lz"],
+        "zlib not found. Install it: `apt install zlib1g-dev`",
+    ),
+];
+
+/// Panic message for a failed build: known causes first, then the end of the log.
+fn build_failure(target: &str, started: Instant, log_path: &Path) -> String {
+    let log = fs::read_to_string(log_path).unwrap_or_default();
+    let lowercase_log = log.to_lowercase();
+    let hints: Vec<&str> = KNOWN_FAILURES
+        .iter()
+        .filter(|(patterns, _)| {
+            patterns
+                .iter()
+                .any(|pattern| lowercase_log.contains(pattern))
+        })
+        .map(|(_, hint)| *hint)
+        .collect();
+    let lines: Vec<&str> = log.lines().collect();
+    let tail = lines[lines.len().saturating_sub(FAILURE_LOG_LINES)..].join("\n");
+
+    let mut message = format!(
+        "Failed to build TON target {target} after {:.1?}\nFull log: {}\n",
+        started.elapsed(),
+        log_path.display()
+    );
+    for hint in hints {
+        message.push_str(&format!("* {hint}\n"));
+    }
+    message.push_str(&format!("--- last lines of the log ---\n{tail}"));
+    message
 }
 
 // function must be safe to handle _lock
-fn checkout_repo(monorepo_dir: &Path) -> anyhow::Result<()> {
+fn checkout_repo(monorepo_dir: &Path, source: &TonSource) -> anyhow::Result<()> {
     if let Some(parent_dir) = monorepo_dir.parent() {
         fs::create_dir_all(parent_dir)?;
     }
 
     if !monorepo_dir.exists() {
-        clone_repo(monorepo_dir)?;
+        clone_repo(monorepo_dir, source)?;
         return Ok(());
     }
 
@@ -189,29 +645,108 @@ fn checkout_repo(monorepo_dir: &Path) -> anyhow::Result<()> {
             monorepo_dir.display()
         );
         fs::remove_dir_all(monorepo_dir)?;
-        clone_repo(monorepo_dir)?;
+        clone_repo(monorepo_dir, source)?;
     }
     Ok(())
 }
 
-fn patch_macos_dsymutil_linker_hook(monorepo_dir: &Path) {
-    if !cfg!(target_os = "macos") {
+/// Replacement of one exact line in TON sources. Unlike `.patch` files it doesn't depend
+/// on surrounding lines, so it keeps applying across TON revisions.
+struct BuiltinPatch {
+    /// Applied only when building for this OS.
+    target_os: Option<&'static str>,
+    file: &'static str,
+    original: &'static str,
+    patched: &'static str,
+}
+
+const BUILTIN_PATCHES: &[BuiltinPatch] = &[
+    // dsymutil linker hook breaks linking with CMake 4
+    BuiltinPatch {
+        target_os: Some("macos"),
+        file: "CMakeLists.txt",
+        original: r#"if(NOT DSYMUTIL_LINK_CONFIGURED AND NOT CMAKE_GENERATOR MATCHES "Xcode" AND CMAKE_BUILD_TYPE MATCHES "Debug|RelWithDebInfo")"#,
+        patched: r#"if(NOT DSYMUTIL_LINK_CONFIGURED AND CMAKE_VERSION VERSION_LESS "4.0" AND NOT CMAKE_GENERATOR MATCHES "Xcode" AND CMAKE_BUILD_TYPE MATCHES "Debug|RelWithDebInfo")"#,
+    },
+];
+
+fn apply_builtin_patch(monorepo_dir: &Path, patch: &BuiltinPatch) {
+    let path = monorepo_dir.join(patch.file);
+    let original = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Failed to read {}: {error}", path.display()));
+
+    if !original.contains(patch.original) || original.contains(patch.patched) {
         return;
     }
 
-    let cmake_lists_path = monorepo_dir.join("CMakeLists.txt");
-    let original = fs::read_to_string(&cmake_lists_path)
-        .unwrap_or_else(|error| panic!("Failed to read {}: {error}", cmake_lists_path.display()));
-    let hook = r#"if(NOT DSYMUTIL_LINK_CONFIGURED AND NOT CMAKE_GENERATOR MATCHES "Xcode" AND CMAKE_BUILD_TYPE MATCHES "Debug|RelWithDebInfo")"#;
-    let guarded_hook = r#"if(NOT DSYMUTIL_LINK_CONFIGURED AND CMAKE_VERSION VERSION_LESS "4.0" AND NOT CMAKE_GENERATOR MATCHES "Xcode" AND CMAKE_BUILD_TYPE MATCHES "Debug|RelWithDebInfo")"#;
+    let patched = original.replace(patch.original, patch.patched);
+    fs::write(&path, patched)
+        .unwrap_or_else(|error| panic!("Failed to patch {}: {error}", path.display()));
+}
 
-    if !original.contains(hook) || original.contains(guarded_hook) {
-        return;
+/// Applies `.patch` files with `git apply`, idempotently: applied patches are remembered,
+/// patches removed or changed since the previous build are reverted first.
+fn apply_patches(monorepo_dir: &Path, patches: &[PathBuf]) -> anyhow::Result<()> {
+    let applied_dir = monorepo_dir.join(APPLIED_PATCHES_DIR);
+    fs::create_dir_all(&applied_dir)?;
+
+    let mut applied: Vec<PathBuf> = fs::read_dir(&applied_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect();
+    applied.sort();
+    for applied_patch in applied.iter().rev() {
+        let wanted = patches.iter().any(|patch| {
+            patch.file_name() == applied_patch.file_name()
+                && fs::read(patch).ok() == fs::read(applied_patch).ok()
+        });
+        if !wanted {
+            git_apply(monorepo_dir, applied_patch, true)?;
+            fs::remove_file(applied_patch)?;
+            println!("Reverted TON patch {}", applied_patch.display());
+        }
     }
 
-    let patched = original.replace(hook, guarded_hook);
-    fs::write(&cmake_lists_path, patched)
-        .unwrap_or_else(|error| panic!("Failed to patch {}: {error}", cmake_lists_path.display()));
+    for patch in patches {
+        let Some(name) = patch.file_name() else {
+            continue;
+        };
+        let applied_patch = applied_dir.join(name);
+        if applied_patch.exists() {
+            continue;
+        }
+        let already_applied = Command::new("git")
+            .current_dir(monorepo_dir)
+            .args(["apply", "--check", "--reverse"])
+            .arg(patch)
+            .output()?
+            .status
+            .success();
+        if !already_applied {
+            git_apply(monorepo_dir, patch, false)?;
+            println!("Applied TON patch {}", patch.display());
+        }
+        fs::copy(patch, applied_patch)?;
+    }
+    Ok(())
+}
+
+fn git_apply(monorepo_dir: &Path, patch: &Path, reverse: bool) -> anyhow::Result<()> {
+    let mut command = Command::new("git");
+    command.current_dir(monorepo_dir).arg("apply");
+    if reverse {
+        command.arg("--reverse");
+    }
+    let output = command.arg(patch).output()?;
+    if !output.status.success() {
+        bail!(
+            "git apply{} {} failed: {}",
+            if reverse { " --reverse" } else { "" },
+            patch.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
 }
 
 fn repo_is_healthy(monorepo_dir: &Path) -> bool {
@@ -236,7 +771,7 @@ fn repo_is_healthy(monorepo_dir: &Path) -> bool {
     //     .all(|line| line.is_empty() || line.starts_with(' '))
 }
 
-fn clone_repo(monorepo_dir: &Path) -> anyhow::Result<()> {
+fn clone_repo(monorepo_dir: &Path, source: &TonSource) -> anyhow::Result<()> {
     if monorepo_dir.exists() {
         fs::remove_dir_all(monorepo_dir)?;
     }
@@ -244,7 +779,7 @@ fn clone_repo(monorepo_dir: &Path) -> anyhow::Result<()> {
     let clone_status = Command::new("git")
         .arg("clone")
         .arg("--branch")
-        .arg(TON_MONOREPO_REVISION)
+        .arg(&source.revision)
         .arg("--depth")
         .arg("1")
         .arg("--no-tags")
@@ -256,7 +791,7 @@ fn clone_repo(monorepo_dir: &Path) -> anyhow::Result<()> {
         .arg("--also-filter-submodules")
         .arg("--jobs")
         .arg("8")
-        .arg(TON_MONOREPO_URL)
+        .arg(&source.url)
         .arg(monorepo_dir)
         .status()?;
 
@@ -271,13 +806,22 @@ fn clone_repo(monorepo_dir: &Path) -> anyhow::Result<()> {
             .arg("--also-filter-submodules")
             .arg("--jobs")
             .arg("8")
-            .arg(TON_MONOREPO_URL)
+            .arg(&source.url)
             .arg(monorepo_dir)
             .status()?;
 
         if !full_clone_status.success() {
             bail!("Failed to clone repository!");
         }
+        // revision may be a commit, which can't be cloned by `--branch`
+        for args in [
+            vec!["checkout", source.revision.as_str()],
+            vec!["submodule", "update", "--init", "--recursive"],
+        ] {
+            if git_output(monorepo_dir, &args).is_none() {
+                bail!("git {} failed", args.join(" "));
+            }
+        }
     };
 
     println!("Cloned repository successfully!");
@@ -329,25 +873,93 @@ fn repo_lock(monorepo_dir: &Path) -> File {
     }
 }
 
-#[cfg(feature = "no_avx512")]
-fn disable_avx512_for_rustc() {
-    println!("cargo:rustc-env=RUSTFLAGS=-C target-feature=-avx512f,-avx512dq,-avx512cd,-avx512bw,-avx512vl,-avx512ifma,-avx512vbmi,-vpclmulqdq");
+/// AVX-512 only exists on x86_64, other targets are not affected by `no_avx512`.
+fn no_avx512(cargo_target: &CargoTarget) -> bool {
+    cfg!(feature = "no_avx512") && cargo_target.arch == "x86_64"
 }
 
-// for clang we just ignore unknown instructions
-#[cfg(all(feature = "no_avx512", not(target_os = "macos")))]
-fn disable_avx512_for_gcc(dst: &mut Config) -> &mut Config {
-    let disable_avx512 = "-mno-avx512f -mno-avx512dq -mno-avx512cd -mno-avx512bw -mno-avx512vl -mno-avx512ifma -mno-avx512vbmi -mno-vpclmulqdq";
-    let compiler_flags = format!("-Wno-unused-command-line-argument {}", disable_avx512);
-    for flag in &[
-        "CMAKE_C_FLAGS",
-        "CMAKE_CXX_FLAGS",
-        "CMAKE_C_FLAGS_RELEASE",
-        "CMAKE_CXX_FLAGS_RELEASE",
-    ] {
-        dst.define(flag, compiler_flags.as_str());
+/// Build scripts can't change codegen of other crates, so RUSTFLAGS enabling AVX-512
+/// (i.e. `-C target-cpu=native` on an AVX-512 machine) are only reported.
+fn warn_rustc_avx512() {
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let avx512: Vec<&str> = target_features
+        .split(',')
+        .filter(|feature| feature.starts_with("avx512"))
+        .collect();
+    if !avx512.is_empty() {
+        println!(
+            "cargo:warning=no_avx512: rustc may still emit AVX-512 ({}), add `-C target-feature=-avx512f` to RUSTFLAGS",
+            avx512.join(",")
+        );
     }
-    dst.asmflag(disable_avx512)
+}
+
+/// Fails the build if TON libraries contain AVX-512 instructions.
+///
+/// OpenSSL is skipped: its assembly picks AVX-512 code paths at runtime via CPUID.
+fn verify_no_avx512(build_dir: &Path, cargo_target: &CargoTarget, toolchain: &Toolchain) {
+    if lto_config(cargo_target, toolchain).is_some() {
+        println!(
+            "cargo:warning=no_avx512: LTO objects can't be disassembled, AVX-512 check skipped"
+        );
+        return;
+    }
+    if Command::new("objdump").arg("--version").output().is_err() {
+        println!("cargo:warning=no_avx512: objdump not found, AVX-512 check skipped");
+        return;
+    }
+    let mut archives = vec![];
+    collect_archives(&build_dir.join("build"), &mut archives);
+    for archive in archives {
+        if let Some(instruction) = find_avx512_instruction(&archive) {
+            panic!(
+                "no_avx512: {} contains AVX-512 instruction `{instruction}`",
+                archive.display()
+            );
+        }
+    }
+}
+
+fn collect_archives(dir: &Path, archives: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            if !path.ends_with("third-party/openssl") {
+                collect_archives(&path, archives);
+            }
+        } else if path.extension().is_some_and(|extension| extension == "a") {
+            archives.push(path);
+        }
+    }
+}
+
+/// First instruction which is EVEX encoded or uses opmask registers, as printed by objdump.
+fn find_avx512_instruction(archive: &Path) -> Option<String> {
+    // disassembly of tonlib is huge, so it is streamed
+    let mut objdump = Command::new("objdump")
+        .arg("-d")
+        .arg(archive)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let stdout = BufReader::new(objdump.stdout.take()?);
+    let instruction = stdout.lines().map_while(Result::ok).find_map(|line| {
+        // `  1f:\t62 f1 7d 48 fe c1 \tvpaddd %zmm1,%zmm0,%zmm0`
+        let mut columns = line.split('\t');
+        let _address = columns.next()?;
+        let bytes = columns.next()?;
+        let instruction = columns.next()?.trim();
+        // 0x62 is EVEX in 64-bit mode, k* instructions work with AVX-512 opmask registers
+        let evex = bytes.trim_start().starts_with("62 ");
+        let opmask = instruction.starts_with('k');
+        (evex || opmask).then(|| instruction.to_owned())
+    });
+    let _ = objdump.kill();
+    let _ = objdump.wait();
+    instruction
 }
 
 #[cfg(target_os = "macos")]
@@ -393,14 +1005,13 @@ fn install_macos_deps() {
     env::set_var("OPENSSL_ROOT_DIR", openssl);
     env::set_var("OPENSSL_INCLUDE_DIR", format!("{openssl}/include"));
     env::set_var("OPENSSL_CRYPTO_LIBRARY", format!("{openssl}/lib"));
-    env::set_var("CXX", "clang++");
 
     println!("cargo:rustc-link-search=native={openssl}/lib");
     println!("cargo:rustc-link-search=native={libsodium}/lib");
     println!("cargo:rustc-link-search=native={secp256k1}/lib");
 }
 
-fn resolve_monorepo_dir() -> PathBuf {
+fn resolve_monorepo_dir(source: &TonSource) -> PathBuf {
     if let Some(dir) = env::var_os(TON_MONOREPO_DIR_ENV) {
         return PathBuf::from(dir);
     }
@@ -409,22 +1020,642 @@ fn resolve_monorepo_dir() -> PathBuf {
         .map(PathBuf::from)
         .unwrap_or_else(|_| dirs::home_dir().unwrap().join(".cargo"));
 
-    let repo_dir = format!("git/db/tonlibsys_ton_{TON_MONOREPO_REVISION}");
-    cargo_home.join(repo_dir)
+    cargo_home.join("git/db").join(source.dir_name())
 }
 
-fn resolve_shared_build_dir(monorepo_dir: &Path) -> PathBuf {
-    let target = env::var("TARGET").unwrap_or_else(|_| "unknown-target".to_owned());
+/// Build directory is keyed by everything that affects produced artifacts,
+/// so changing compiler, CMake defines or features never reuses incompatible objects.
+/// Directories of stale configurations can be removed with `tonlib-sys-cache prune`.
+///
+/// Each cargo target gets its own `target/<triple>` directory, so host and cross builds never mix.
+fn resolve_shared_build_dir(
+    monorepo_dir: &Path,
+    source: &TonSource,
+    cargo_target: &CargoTarget,
+    toolchain: &Toolchain,
+    defines: &[(&str, &str)],
+    toolchain_file: Option<&str>,
+) -> PathBuf {
+    let target = &cargo_target.triple;
     let profile = env::var("PROFILE").unwrap_or_else(|_| "unknown-profile".to_owned());
     let feature_suffix = if cfg!(feature = "no_avx512") {
         "-no_avx512"
     } else {
         ""
     };
-    let build_dir_name = format!("{profile}-{CMAKE_BUILD_TYPE}{feature_suffix}");
+    let build_key = build_key(
+        source,
+        cargo_target,
+        &profile,
+        toolchain,
+        defines,
+        toolchain_file,
+    );
+    let build_dir_name = format!(
+        "{profile}-{CMAKE_BUILD_TYPE}{feature_suffix}-{:016x}",
+        fnv1a(build_key.as_bytes())
+    );
 
-    monorepo_dir
+    let build_dir = monorepo_dir
         .join("target")
         .join(target)
-        .join(build_dir_name)
+        .join(build_dir_name);
+    fs::create_dir_all(&build_dir)
+        .unwrap_or_else(|error| panic!("Failed to create {}: {error}", build_dir.display()));
+    fs::write(build_dir.join(BUILD_KEY_FILE), &build_key)
+        .unwrap_or_else(|error| panic!("Failed to write build key: {error}"));
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    fs::write(build_dir.join(BUILD_LAST_USED_FILE), now.to_string())
+        .unwrap_or_else(|error| panic!("Failed to write build timestamp: {error}"));
+    build_dir
+}
+
+/// Human-readable description of the build configuration, hashed into the directory name.
+fn build_key(
+    source: &TonSource,
+    cargo_target: &CargoTarget,
+    profile: &str,
+    toolchain: &Toolchain,
+    defines: &[(&str, &str)],
+    toolchain_file: Option<&str>,
+) -> String {
+    let mut lines = vec![
+        format!("revision={}", source.revision),
+        format!("target={}", cargo_target.triple),
+        format!("profile={profile}"),
+    ];
+    if source.url != DEFAULT_TON_MONOREPO_URL {
+        lines.push(format!("url={}", source.url));
+    }
+    if cargo_target.is_cross() {
+        lines.push(format!("host={}", cargo_target.host));
+    }
+    for patch in &source.patches {
+        let content = fs::read(patch).unwrap_or_default();
+        lines.push(format!(
+            "patch {} {:016x}",
+            patch.file_name().unwrap_or_default().to_string_lossy(),
+            fnv1a(&content)
+        ));
+    }
+    lines.push(format!(
+        "cc={} {}",
+        toolchain.cc,
+        compiler_identity(&toolchain.cc)
+    ));
+    lines.push(format!(
+        "cxx={} {}",
+        toolchain.cxx,
+        compiler_identity(&toolchain.cxx)
+    ));
+
+    let mut defines = defines.to_vec();
+    defines.sort();
+    lines.extend(
+        defines
+            .iter()
+            .map(|(name, value)| format!("define {name}={value}")),
+    );
+
+    lines.extend(
+        toolchain_file
+            .into_iter()
+            .flat_map(str::lines)
+            .map(|line| format!("toolchain {line}")),
+    );
+
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(name, _)| name.strip_prefix("CARGO_FEATURE_").map(str::to_lowercase))
+        .collect();
+    features.sort();
+    lines.push(format!("features={}", features.join(",")));
+
+    for flags in ["CFLAGS", "CXXFLAGS", "ASMFLAGS"] {
+        println!("cargo:rerun-if-env-changed={flags}");
+        if let Ok(value) = env::var(flags) {
+            lines.push(format!("{flags}={value}"));
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// First line of `--version` output, i.e. `Ubuntu clang version 21.1.0`.
+fn compiler_identity(compiler: &str) -> String {
+    Command::new(compiler)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .next()
+                .map(str::to_owned)
+        })
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+struct Toolchain {
+    cc: String,
+    cxx: String,
+    /// Where the compilers came from, printed to the build log.
+    source: String,
+}
+
+/// Target being built for. `cfg!` in a build script describes the host,
+/// so target-dependent decisions use this instead.
+struct CargoTarget {
+    triple: String,
+    host: String,
+    arch: String,
+    os: String,
+}
+
+impl CargoTarget {
+    fn from_env() -> Self {
+        let var = |name: &str| env::var(name).unwrap_or_else(|_| panic!("{name} is not set"));
+        Self {
+            triple: var("TARGET"),
+            host: var("HOST"),
+            arch: var("CARGO_CFG_TARGET_ARCH"),
+            os: var("CARGO_CFG_TARGET_OS"),
+        }
+    }
+
+    fn is_cross(&self) -> bool {
+        self.triple != self.host
+    }
+
+    fn is_emscripten(&self) -> bool {
+        self.os == "emscripten"
+    }
+
+    /// `aarch64-linux-gnu` for `aarch64-unknown-linux-gnu`: prefix of cross compilers
+    /// and name of Debian multiarch library directories.
+    fn gnu_triple(&self) -> String {
+        self.triple.replacen("-unknown-", "-", 1)
+    }
+}
+
+/// Sysroot given by `TONLIB_SYS_SYSROOT`. Without it, libraries of the target are looked up
+/// where Debian/Ubuntu cross packages (`libssl-dev:arm64`, `gcc-aarch64-linux-gnu`) install them.
+fn cross_sysroot() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed={TONLIB_SYS_SYSROOT_ENV}");
+    env::var_os(TONLIB_SYS_SYSROOT_ENV).map(PathBuf::from)
+}
+
+/// CMake toolchain file for cross builds, `None` for native ones.
+fn cross_toolchain_file(cargo_target: &CargoTarget) -> Option<String> {
+    if !cargo_target.is_cross() {
+        return None;
+    }
+    let system_name = match cargo_target.os.as_str() {
+        "linux" => "Linux",
+        "macos" => "Darwin",
+        // the cmake crate runs `emcmake` for emscripten, which brings its own toolchain file
+        _ => return None,
+    };
+    let gnu_triple = cargo_target.gnu_triple();
+    let mut lines = vec![
+        format!("set(CMAKE_SYSTEM_NAME {system_name})"),
+        format!("set(CMAKE_SYSTEM_PROCESSOR {})", cargo_target.arch),
+        format!("set(CMAKE_C_COMPILER_TARGET {gnu_triple})"),
+        format!("set(CMAKE_CXX_COMPILER_TARGET {gnu_triple})"),
+        format!("set(CMAKE_ASM_COMPILER_TARGET {gnu_triple})"),
+        format!("set(CMAKE_LIBRARY_ARCHITECTURE {gnu_triple})"),
+    ];
+    match cross_sysroot() {
+        Some(sysroot) => {
+            lines.push(format!("set(CMAKE_SYSROOT \"{}\")", sysroot.display()));
+            lines.push(format!(
+                "set(CMAKE_FIND_ROOT_PATH \"{}\")",
+                sysroot.display()
+            ));
+        }
+        None => lines.push(format!("set(CMAKE_FIND_ROOT_PATH /usr/{gnu_triple} /)")),
+    }
+    lines.extend([
+        // build tools run on the host, everything linked comes from the target
+        "set(CMAKE_FIND_ROOT_PATH_MODE_PROGRAM NEVER)".to_owned(),
+        "set(CMAKE_FIND_ROOT_PATH_MODE_LIBRARY ONLY)".to_owned(),
+        "set(CMAKE_FIND_ROOT_PATH_MODE_INCLUDE ONLY)".to_owned(),
+        "set(CMAKE_FIND_ROOT_PATH_MODE_PACKAGE ONLY)".to_owned(),
+    ]);
+    Some(lines.join("\n") + "\n")
+}
+
+fn wasm_deps_dir() -> PathBuf {
+    println!("cargo:rerun-if-env-changed={TONLIB_SYS_WASM_DEPS_ENV}");
+    env::var_os(TONLIB_SYS_WASM_DEPS_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            panic!(
+                "{TONLIB_SYS_WASM_DEPS_ENV} must point to OpenSSL, libsodium, secp256k1 and zlib built with emscripten"
+            )
+        })
+}
+
+/// TON can't find dependencies of an emscripten build by itself, so they are passed explicitly,
+/// the same way TON's own wasm build scripts do.
+fn wasm_dep_defines() -> Vec<(&'static str, String)> {
+    let deps_dir = wasm_deps_dir();
+    let include = deps_dir.join("include").display().to_string();
+    let lib = |name: &str| {
+        let path = deps_dir.join("lib").join(format!("lib{name}.a"));
+        if !path.is_file() {
+            panic!("{} not found in {TONLIB_SYS_WASM_DEPS_ENV}", path.display());
+        }
+        path.display().to_string()
+    };
+    vec![
+        ("OPENSSL_FOUND", "1".to_owned()),
+        ("OPENSSL_ROOT_DIR", deps_dir.display().to_string()),
+        ("OPENSSL_INCLUDE_DIR", include.clone()),
+        ("OPENSSL_CRYPTO_LIBRARY", lib("crypto")),
+        ("ZLIB_FOUND", "1".to_owned()),
+        ("ZLIB_INCLUDE_DIR", include.clone()),
+        ("ZLIB_LIBRARIES", lib("z")),
+        ("SODIUM_FOUND", "1".to_owned()),
+        ("SODIUM_USE_STATIC_LIBS", "1".to_owned()),
+        ("SODIUM_INCLUDE_DIR", include.clone()),
+        ("SODIUM_LIBRARY_RELEASE", lib("sodium")),
+        ("SECP256K1_FOUND", "1".to_owned()),
+        ("SECP256K1_INCLUDE_DIR", include),
+        ("SECP256K1_LIBRARY", lib("secp256k1")),
+    ]
+}
+
+/// `-fsanitize` flags enabled by `asan`/`ubsan` features, empty without them.
+fn sanitizer_flags() -> String {
+    let mut sanitizers = vec![];
+    if cfg!(feature = "asan") {
+        sanitizers.push("address");
+    }
+    if cfg!(feature = "ubsan") {
+        sanitizers.push("undefined");
+    }
+    if sanitizers.is_empty() {
+        return String::new();
+    }
+    format!(
+        "-fsanitize={} -fno-omit-frame-pointer",
+        sanitizers.join(",")
+    )
+}
+
+/// LTO settings of `lto` feature: ThinLTO for clang, regular LTO for gcc.
+struct Lto {
+    flag: &'static str,
+    /// `ar` and `ranlib` which index LTO objects, `None` if the default ones do.
+    archiver: Option<(String, String)>,
+    /// Arguments of the final link, its linker must understand LTO objects of the compiler.
+    /// Empty if the default link handles them.
+    link_args: &'static [&'static str],
+    /// Compiler driver required as the cargo linker for `link_args`, `None` if any will do.
+    linker: Option<&'static str>,
+}
+
+fn lto_config(cargo_target: &CargoTarget, toolchain: &Toolchain) -> Option<Lto> {
+    if !cfg!(feature = "lto") || cargo_target.is_emscripten() {
+        return None;
+    }
+    // `clang-21` -> `llvm-ar-21`, `aarch64-linux-gnu-gcc` -> `aarch64-linux-gnu-gcc-ar`
+    let tool = |compiler: &str, tool: &str| {
+        sibling_tool(&toolchain.cc, compiler, tool).unwrap_or_else(|| tool.to_owned())
+    };
+    if compiler_identity(&toolchain.cc).contains("clang") {
+        // Apple's ar and ld64 handle bitcode via libLTO
+        if cargo_target.os == "macos" {
+            return Some(Lto {
+                flag: "-flto=thin",
+                archiver: None,
+                link_args: &[],
+                linker: None,
+            });
+        }
+        Some(Lto {
+            flag: "-flto=thin",
+            archiver: Some((tool("clang", "llvm-ar"), tool("clang", "llvm-ranlib"))),
+            link_args: &["-flto=thin", "-fuse-ld=lld"],
+            linker: Some("clang"),
+        })
+    } else {
+        Some(Lto {
+            flag: "-flto=auto",
+            archiver: Some((tool("gcc", "gcc-ar"), tool("gcc", "gcc-ranlib"))),
+            link_args: &["-flto=auto"],
+            linker: None,
+        })
+    }
+}
+
+/// Fails the build before compiling TON if binaries linking it can't handle its LTO objects.
+///
+/// `cargo:rustc-link-arg` reaches only this crate's own tests and bins, so link arguments have
+/// to come from RUSTFLAGS, which every binary of the build is linked with. The linker is
+/// `CARGO_TARGET_<TRIPLE>_LINKER`, `RUSTC_LINKER` (set by cargo from `target.<triple>.linker`)
+/// or rustc's default `cc`.
+fn check_lto_link(cargo_target: &CargoTarget, lto: &Lto) {
+    let link_args: Vec<String> = rustc_codegen_options()
+        .iter()
+        .flat_map(|option| {
+            if let Some(arg) = option.strip_prefix("link-arg=") {
+                vec![arg.to_owned()]
+            } else if let Some(args) = option.strip_prefix("link-args=") {
+                args.split_whitespace().map(str::to_owned).collect()
+            } else {
+                vec![]
+            }
+        })
+        .collect();
+    let missing: Vec<&str> = lto
+        .link_args
+        .iter()
+        .copied()
+        .filter(|arg| !link_args.iter().any(|link_arg| link_arg == arg))
+        .collect();
+    if !missing.is_empty() {
+        let rustflags = missing
+            .iter()
+            .map(|arg| format!("-C link-arg={arg}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        panic!(
+            "Feature `lto` builds TON as LTO objects, every binary linking tonlib-sys has to be linked with {}. Add `{rustflags}` to RUSTFLAGS or `target.{}.rustflags` in .cargo/config.toml",
+            missing.join(" "),
+            cargo_target.triple
+        );
+    }
+
+    let Some(compiler) = lto.linker else {
+        return;
+    };
+    let linker_env = format!(
+        "CARGO_TARGET_{}_LINKER",
+        cargo_target.triple.to_uppercase().replace(['-', '.'], "_")
+    );
+    println!("cargo:rerun-if-env-changed={linker_env}");
+    println!("cargo:rerun-if-env-changed=RUSTC_LINKER");
+    let linker = [linker_env.as_str(), "RUSTC_LINKER"]
+        .into_iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.trim().is_empty()))
+        .unwrap_or_else(|| "cc".to_owned());
+    let identity = compiler_identity(&linker);
+    if !identity.contains(compiler) {
+        panic!(
+            "Feature `lto` needs {compiler} as the linker to link TON's LTO objects, but rustc links with `{linker}` ({identity}). Set {linker_env}={compiler} (or a versioned {compiler}-<version>)"
+        );
+    }
+}
+
+/// CPU TON is compiled for.
+enum TargetCpu {
+    /// Passed to TON as `TON_ARCH`, which turns it into `-march`.
+    March(String),
+    /// Added to compiler flags, used for rustc CPU names of non-x86 targets (`neoverse-n1`,
+    /// `apple-m1`), which gcc and clang accept only as `-mcpu`.
+    Mcpu(String),
+}
+
+/// CPU for TON: `TONLIB_SYS_MARCH`, `TARGET_CPU_MARCH` or rustc `-C target-cpu`,
+/// so native and Rust code are tuned for the same CPU. `native` is ignored for cross builds,
+/// it describes the build machine.
+fn target_cpu(cargo_target: &CargoTarget) -> Option<TargetCpu> {
+    let not_native = |cpu: &String, source: &str| {
+        let native = cpu == "native" && cargo_target.is_cross();
+        if native {
+            println!(
+                "cargo:warning=tonlib-sys: ignoring {source}=native for cross build to {}",
+                cargo_target.triple
+            );
+        }
+        !native
+    };
+    for name in [TONLIB_SYS_MARCH_ENV, "TARGET_CPU_MARCH"] {
+        println!("cargo:rerun-if-env-changed={name}");
+        if let Some(march) = env::var(name).ok().filter(|march| !march.trim().is_empty()) {
+            return not_native(&march, name).then_some(TargetCpu::March(march));
+        }
+    }
+    let cpu = rustc_target_cpu().filter(|cpu| not_native(cpu, "-C target-cpu"))?;
+    if matches!(cargo_target.arch.as_str(), "x86" | "x86_64") {
+        Some(TargetCpu::March(cpu))
+    } else {
+        Some(TargetCpu::Mcpu(cpu))
+    }
+}
+
+/// Last `-C target-cpu` of RUSTFLAGS, the one rustc uses.
+fn rustc_target_cpu() -> Option<String> {
+    rustc_codegen_options()
+        .iter()
+        .rev()
+        .find_map(|option| option.strip_prefix("target-cpu="))
+        .map(str::to_owned)
+}
+
+/// Values of `-C`/`--codegen` options in RUSTFLAGS, in order.
+fn rustc_codegen_options() -> Vec<String> {
+    let Ok(rustflags) = env::var("CARGO_ENCODED_RUSTFLAGS") else {
+        return vec![];
+    };
+    let mut flags = rustflags.split('\x1f');
+    let mut options = vec![];
+    while let Some(flag) = flags.next() {
+        let codegen = match flag {
+            "-C" | "--codegen" => flags.next(),
+            _ => flag
+                .strip_prefix("-C")
+                .or_else(|| flag.strip_prefix("--codegen=")),
+        };
+        options.extend(codegen.map(str::to_owned));
+    }
+    options
+}
+
+/// Adds search path of `lib<name>.a`, preferring archives TON built from its third-party tree
+/// over static libraries installed in the system.
+fn link_search_static(name: &str, build_dir: &str, cargo_target: &CargoTarget) {
+    let third_party = Path::new(build_dir).join("build/third-party");
+    let mut dirs = vec![
+        third_party.join(name),
+        third_party.join(name).join("lib"),
+        third_party.join(format!("lib{name}")).join("lib"),
+    ];
+    if cargo_target.is_cross() {
+        dirs.extend(cross_library_dirs(cargo_target));
+    } else {
+        dirs.extend(
+            [
+                format!("/usr/lib/{}", cargo_target.gnu_triple()),
+                "/usr/lib64".to_owned(),
+                "/usr/lib".to_owned(),
+                "/usr/local/lib".to_owned(),
+                "/opt/homebrew/lib".to_owned(),
+            ]
+            .map(PathBuf::from),
+        );
+    }
+    let file_name = format!("lib{name}.a");
+    match dirs.iter().find(|dir| dir.join(&file_name).is_file()) {
+        Some(dir) => println!("cargo:rustc-link-search=native={}", dir.display()),
+        None => panic!(
+            "portable build needs {file_name}, install static {name} library (i.e. `apt install lib{name}-dev`)"
+        ),
+    }
+}
+
+/// Links `libstdc++.a` reported by the compiler, `-static-libstdc++` has no effect
+/// since rustc passes `-lstdc++` to the linker explicitly.
+fn link_static_libstdcxx(toolchain: &Toolchain) {
+    let output = Command::new(&toolchain.cxx)
+        .arg("-print-file-name=libstdc++.a")
+        .output()
+        .unwrap_or_else(|error| panic!("Failed to run {}: {error}", toolchain.cxx));
+    let path = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    // the bare file name is printed back if the archive is not found
+    let Some(dir) = path
+        .parent()
+        .filter(|_| path.is_absolute() && path.is_file())
+    else {
+        panic!(
+            "{} can't find libstdc++.a, install static libstdc++",
+            toolchain.cxx
+        );
+    };
+    println!("cargo:rustc-link-search=native={}", dir.display());
+    println!("cargo:rustc-link-lib=static=stdc++");
+}
+
+/// Directories with system libraries (libcrypto, libsodium, ...) of the cross target.
+fn cross_library_dirs(cargo_target: &CargoTarget) -> Vec<PathBuf> {
+    let gnu_triple = cargo_target.gnu_triple();
+    let candidates = match cross_sysroot() {
+        Some(sysroot) => vec![
+            sysroot.join("usr/lib").join(&gnu_triple),
+            sysroot.join("lib").join(&gnu_triple),
+            sysroot.join("usr/lib"),
+            sysroot.join("lib"),
+        ],
+        None => vec![
+            Path::new("/usr/lib").join(&gnu_triple),
+            Path::new("/usr").join(&gnu_triple).join("lib"),
+        ],
+    };
+    candidates.into_iter().filter(|dir| dir.is_dir()).collect()
+}
+
+/// Picks C/C++ compilers for the TON build.
+///
+/// `TONLIB_SYS_CC`/`TONLIB_SYS_CXX` win over `CC`/`CXX`, so TON can be built with a different
+/// compiler than other crates. If only the C compiler is given, the C++ one is derived from it
+/// (`clang-19` -> `clang++-19`, `gcc-13` -> `g++-13`). Without any of them versioned clang
+/// is probed from newest to oldest, then plain `clang` and finally `gcc`.
+fn select_toolchain(cargo_target: &CargoTarget) -> Toolchain {
+    // same per-target variables as the `cc` crate, i.e. CC_aarch64_unknown_linux_gnu
+    let target_suffix = cargo_target.triple.replace('-', "_");
+    let target_cc_env = format!("CC_{target_suffix}");
+    let target_cxx_env = format!("CXX_{target_suffix}");
+    let mut env_pairs = vec![
+        (TONLIB_SYS_CC_ENV, TONLIB_SYS_CXX_ENV),
+        (target_cc_env.as_str(), target_cxx_env.as_str()),
+    ];
+    // generic CC is a native compiler, it can't produce wasm
+    if !cargo_target.is_emscripten() {
+        env_pairs.push(("CC", "CXX"));
+    }
+    for (cc_env, cxx_env) in &env_pairs {
+        println!("cargo:rerun-if-env-changed={cc_env}");
+        println!("cargo:rerun-if-env-changed={cxx_env}");
+    }
+    let non_empty = |name: &str| env::var(name).ok().filter(|value| !value.trim().is_empty());
+
+    for (cc_env, cxx_env) in env_pairs {
+        let Some(cc) = non_empty(cc_env) else {
+            continue;
+        };
+        let cxx = non_empty(cxx_env)
+            .or_else(|| cxx_for(&cc))
+            .unwrap_or_else(|| {
+                panic!("{cc_env}={cc} is set, but C++ compiler can't be derived from it, set {cxx_env} too")
+            });
+        return Toolchain {
+            cc,
+            cxx,
+            source: cc_env.to_owned(),
+        };
+    }
+
+    if cargo_target.is_emscripten() {
+        return Toolchain {
+            cc: "emcc".to_owned(),
+            cxx: "em++".to_owned(),
+            source: "emscripten".to_owned(),
+        };
+    }
+
+    // clang cross compiles by itself given `--target`, gcc needs a cross build of it
+    let gcc_prefix = if cargo_target.is_cross() {
+        format!("{}-", cargo_target.gnu_triple())
+    } else {
+        String::new()
+    };
+
+    let candidates = CLANG_VERSIONS
+        .iter()
+        .map(|version| (format!("clang-{version}"), format!("clang++-{version}")))
+        .chain([
+            ("clang".to_owned(), "clang++".to_owned()),
+            (format!("{gcc_prefix}gcc"), format!("{gcc_prefix}g++")),
+        ]);
+    for (cc, cxx) in candidates {
+        if compiler_available(&cc) && compiler_available(&cxx) {
+            return Toolchain {
+                cc,
+                cxx,
+                source: "probed".to_owned(),
+            };
+        }
+    }
+    panic!(
+        "No C/C++ compiler found: tried clang-{{{}}}, clang and {gcc_prefix}gcc. Install one or set {TONLIB_SYS_CC_ENV}",
+        CLANG_VERSIONS
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+}
+
+/// C++ compiler matching C compiler, i.e. `/usr/bin/clang-19` -> `/usr/bin/clang++-19`.
+fn cxx_for(cc: &str) -> Option<String> {
+    sibling_tool(cc, "clang", "clang++")
+        .or_else(|| sibling_tool(cc, "gcc", "g++"))
+        .or_else(|| sibling_tool(cc, "cc", "c++").filter(|_| cc.rsplit('/').next() == Some("cc")))
+}
+
+/// Tool next to `compiler` with `from` replaced by `to` in its file name, keeping the
+/// directory and version suffix: `/usr/bin/clang-19` -> `/usr/bin/llvm-ar-19`.
+fn sibling_tool(compiler: &str, from: &str, to: &str) -> Option<String> {
+    let name_start = compiler.rfind('/').map_or(0, |slash| slash + 1);
+    let (dir, name) = compiler.split_at(name_start);
+    name.contains(from)
+        .then(|| format!("{dir}{}", name.replacen(from, to, 1)))
+}
+
+fn compiler_available(compiler: &str) -> bool {
+    Command::new(compiler)
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// FNV-1a, stable across Rust releases unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
 }
@@ -0,0 +1,267 @@
+//! Lists and prunes TON monorepo build directories created by the tonlib-sys build script.
+//!
+//! ```text
+//! tonlib-sys-cache list [--monorepo DIR]
+//! tonlib-sys-cache prune [--monorepo DIR] [--older-than DAYS] [--other-revisions] [--dry-run]
+//! ```
+//!
+//! Without `--monorepo` (or `TON_MONOREPO_DIR`) all checkouts under `$CARGO_HOME/git/db` are used.
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use fs2::FileExt;
+
+// keep in sync with build.rs
+const BUILD_KEY_FILE: &str = ".tonlib-sys-build-key";
+const BUILD_LAST_USED_FILE: &str = ".tonlib-sys-last-used";
+const MONOREPO_PREFIX: &str = "tonlibsys_ton_";
+// checkout used by this version, depends on TON_MONOREPO_URL/TON_MONOREPO_REVISION at build time
+const TON_MONOREPO_DIR_NAME: &str = env!("TON_MONOREPO_DIR_NAME");
+const DEFAULT_MAX_AGE_DAYS: u64 = 30;
+
+struct Args {
+    command: String,
+    monorepo: Option<PathBuf>,
+    max_age_days: u64,
+    other_revisions: bool,
+    dry_run: bool,
+}
+
+struct Build {
+    dir: PathBuf,
+    /// `None` for directories created before build keys were introduced.
+    last_used: Option<u64>,
+    key: Option<String>,
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{error}");
+            eprintln!(
+                "usage: tonlib-sys-cache list|prune [--monorepo DIR] [--older-than DAYS] [--other-revisions] [--dry-run]"
+            );
+            std::process::exit(2);
+        }
+    };
+    let monorepos = monorepo_dirs(&args);
+    if monorepos.is_empty() {
+        println!("no TON monorepo checkouts found");
+        return;
+    }
+    match args.command.as_str() {
+        "list" => list(&monorepos),
+        "prune" => prune(&monorepos, &args),
+        _ => unreachable!(),
+    }
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let command = args.next().ok_or("missing command")?;
+    if command != "list" && command != "prune" {
+        return Err(format!("unknown command {command}"));
+    }
+    let mut parsed = Args {
+        command,
+        monorepo: std::env::var_os("TON_MONOREPO_DIR").map(PathBuf::from),
+        max_age_days: DEFAULT_MAX_AGE_DAYS,
+        other_revisions: false,
+        dry_run: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--monorepo" => parsed.monorepo = Some(args.next().ok_or("missing DIR")?.into()),
+            "--older-than" => {
+                parsed.max_age_days = args
+                    .next()
+                    .and_then(|days| days.parse().ok())
+                    .ok_or("--older-than expects number of days")?
+            }
+            "--other-revisions" => parsed.other_revisions = true,
+            "--dry-run" => parsed.dry_run = true,
+            _ => return Err(format!("unknown argument {arg}")),
+        }
+    }
+    Ok(parsed)
+}
+
+fn monorepo_dirs(args: &Args) -> Vec<PathBuf> {
+    if let Some(dir) = &args.monorepo {
+        return vec![dir.clone()];
+    }
+    let cargo_home = std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cargo")));
+    let Some(db_dir) = cargo_home.map(|home| home.join("git/db")) else {
+        return vec![];
+    };
+    let Ok(entries) = fs::read_dir(db_dir) else {
+        return vec![];
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && revision(path).is_some())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn revision(monorepo_dir: &Path) -> Option<&str> {
+    monorepo_dir
+        .file_name()?
+        .to_str()?
+        .strip_prefix(MONOREPO_PREFIX)
+}
+
+fn is_current(monorepo_dir: &Path) -> bool {
+    monorepo_dir
+        .file_name()
+        .is_some_and(|name| name == TON_MONOREPO_DIR_NAME)
+}
+
+fn builds(monorepo_dir: &Path) -> Vec<Build> {
+    let mut builds = vec![];
+    let Ok(targets) = fs::read_dir(monorepo_dir.join("target")) else {
+        return builds;
+    };
+    for target in targets.flatten().filter(|entry| entry.path().is_dir()) {
+        let Ok(dirs) = fs::read_dir(target.path()) else {
+            continue;
+        };
+        for dir in dirs.flatten().map(|entry| entry.path()) {
+            if !dir.is_dir() {
+                continue;
+            }
+            builds.push(Build {
+                last_used: fs::read_to_string(dir.join(BUILD_LAST_USED_FILE))
+                    .ok()
+                    .and_then(|time| time.trim().parse().ok()),
+                key: fs::read_to_string(dir.join(BUILD_KEY_FILE)).ok(),
+                dir,
+            });
+        }
+    }
+    builds.sort_by(|a, b| a.dir.cmp(&b.dir));
+    builds
+}
+
+fn list(monorepos: &[PathBuf]) {
+    let now = now();
+    for monorepo in monorepos {
+        let current = if is_current(monorepo) {
+            " (current revision)"
+        } else {
+            ""
+        };
+        println!("{}{current}", monorepo.display());
+        for build in builds(monorepo) {
+            let age = match build.last_used {
+                Some(last_used) => {
+                    format!("used {} days ago", now.saturating_sub(last_used) / 86400)
+                }
+                None => "legacy, never used by this version".to_owned(),
+            };
+            println!(
+                "  {} [{}, {}]",
+                build.dir.display(),
+                age,
+                format_size(dir_size(&build.dir))
+            );
+            for line in build.key.iter().flat_map(|key| key.lines()) {
+                if line.starts_with("cc=") || line.starts_with("features=") {
+                    println!("    {line}");
+                }
+            }
+        }
+    }
+}
+
+fn prune(monorepos: &[PathBuf], args: &Args) {
+    let min_last_used = now().saturating_sub(args.max_age_days * 86400);
+    let mut freed = 0;
+    for monorepo in monorepos {
+        let Some(_lock) = lock(monorepo) else {
+            println!("skipping {}: build in progress", monorepo.display());
+            continue;
+        };
+        let other_revision = revision(monorepo).is_some() && !is_current(monorepo);
+        if args.other_revisions && other_revision {
+            freed += remove(monorepo, args.dry_run);
+            continue;
+        }
+        for build in builds(monorepo) {
+            if build
+                .last_used
+                .is_none_or(|last_used| last_used < min_last_used)
+            {
+                freed += remove(&build.dir, args.dry_run);
+            }
+        }
+    }
+    let verb = if args.dry_run { "would free" } else { "freed" };
+    println!("{verb} {}", format_size(freed));
+}
+
+/// Same lock as taken by the build script, so builds in progress are not removed.
+fn lock(monorepo_dir: &Path) -> Option<File> {
+    let lock_path = monorepo_dir.with_file_name(format!(
+        "{}.lock",
+        monorepo_dir.file_name()?.to_string_lossy()
+    ));
+    let file = File::options()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(lock_path)
+        .ok()?;
+    file.try_lock_exclusive().ok()?;
+    Some(file)
+}
+
+fn remove(dir: &Path, dry_run: bool) -> u64 {
+    let size = dir_size(dir);
+    if dry_run {
+        println!("would remove {} ({})", dir.display(), format_size(size));
+        return size;
+    }
+    match fs::remove_dir_all(dir) {
+        Ok(()) => {
+            println!("removed {} ({})", dir.display(), format_size(size));
+            size
+        }
+        Err(error) => {
+            eprintln!("failed to remove {}: {error}", dir.display());
+            0
+        }
+    }
+}
+
+fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => dir_size(&entry.path()),
+            Ok(_) => entry.metadata().map(|m| m.len()).unwrap_or_default(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+fn format_size(bytes: u64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
@@ -0,0 +1,610 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+
+const BOC_MAGIC: u32 = 0xb5ee9c72;
+const MAX_CELL_BITS: usize = 1023;
+const MAX_CELL_REFS: usize = 4;
+const LIBRARY_CELL_TYPE: u8 = 2;
+
+pub type CellHash = [u8; 32];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellError(String);
+
+impl CellError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl fmt::Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CellError {}
+
+/// Minimal TVM cell: enough to build and inspect BoCs passed to the emulators.
+/// Only level 0 cells (ordinary and library references) are supported.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Cell {
+    data: Vec<u8>,
+    bit_len: usize,
+    references: Vec<Arc<Cell>>,
+    exotic: bool,
+    hash: CellHash,
+    depth: u16,
+}
+
+impl Cell {
+    pub fn new(
+        data: Vec<u8>,
+        bit_len: usize,
+        references: Vec<Arc<Cell>>,
+        exotic: bool,
+    ) -> Result<Self, CellError> {
+        if bit_len > MAX_CELL_BITS || data.len() != bit_len.div_ceil(8) {
+            return Err(CellError::new(format!("invalid cell bit length {bit_len}")));
+        }
+        if references.len() > MAX_CELL_REFS {
+            return Err(CellError::new("too many cell references"));
+        }
+        if exotic && (bit_len < 8 || data[0] != LIBRARY_CELL_TYPE) {
+            return Err(CellError::new("only library exotic cells are supported"));
+        }
+        let mut data = data;
+        if !bit_len.is_multiple_of(8) {
+            let last = data.len() - 1;
+            data[last] &= 0xff << (8 - bit_len % 8);
+        }
+
+        let depth = references
+            .iter()
+            .map(|reference| reference.depth + 1)
+            .max()
+            .unwrap_or(0);
+        let mut hasher = Sha256::new();
+        hasher.update(descriptors(references.len(), exotic, bit_len));
+        hasher.update(padded_data(&data, bit_len));
+        for reference in &references {
+            hasher.update(reference.depth.to_be_bytes());
+        }
+        for reference in &references {
+            hasher.update(reference.hash);
+        }
+        Ok(Self {
+            data,
+            bit_len,
+            references,
+            exotic,
+            hash: hasher.finalize().into(),
+            depth,
+        })
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    pub fn references(&self) -> &[Arc<Cell>] {
+        &self.references
+    }
+
+    pub fn is_exotic(&self) -> bool {
+        self.exotic
+    }
+
+    /// Representation hash.
+    pub fn hash(&self) -> CellHash {
+        self.hash
+    }
+
+    pub fn hash_hex(&self) -> String {
+        self.hash.iter().map(|b| format!("{b:02X}")).collect()
+    }
+
+    pub fn depth(&self) -> u16 {
+        self.depth
+    }
+
+    pub fn parser(&self) -> CellParser<'_> {
+        CellParser {
+            cell: self,
+            bit_pos: 0,
+            ref_pos: 0,
+        }
+    }
+
+    pub fn from_boc(boc: &[u8]) -> Result<Vec<Arc<Cell>>, CellError> {
+        parse_boc(boc)
+    }
+
+    /// Parses base64 encoded BoC with a single root.
+    pub fn from_boc_b64(boc: &str) -> Result<Arc<Cell>, CellError> {
+        let boc = STANDARD
+            .decode(boc.trim())
+            .map_err(|e| CellError::new(format!("invalid base64: {e}")))?;
+        let mut roots = parse_boc(&boc)?;
+        if roots.len() != 1 {
+            return Err(CellError::new(format!(
+                "expected single root, got {}",
+                roots.len()
+            )));
+        }
+        Ok(roots.remove(0))
+    }
+
+    pub fn to_boc(&self) -> Vec<u8> {
+        serialize_boc(self)
+    }
+
+    pub fn to_boc_b64(&self) -> String {
+        STANDARD.encode(self.to_boc())
+    }
+}
+
+impl fmt::Debug for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cell")
+            .field("hash", &self.hash_hex())
+            .field("bit_len", &self.bit_len)
+            .field("references", &self.references.len())
+            .field("exotic", &self.exotic)
+            .finish()
+    }
+}
+
+fn descriptors(ref_count: usize, exotic: bool, bit_len: usize) -> [u8; 2] {
+    let d1 = ref_count as u8 + if exotic { 8 } else { 0 };
+    let d2 = (bit_len / 8 + bit_len.div_ceil(8)) as u8;
+    [d1, d2]
+}
+
+fn padded_data(data: &[u8], bit_len: usize) -> Vec<u8> {
+    let mut data = data.to_vec();
+    if !bit_len.is_multiple_of(8) {
+        let last = data.len() - 1;
+        data[last] |= 0x80 >> (bit_len % 8);
+    }
+    data
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CellBuilder {
+    data: Vec<u8>,
+    bit_len: usize,
+    references: Vec<Arc<Cell>>,
+}
+
+impl CellBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    pub fn store_bit(&mut self, bit: bool) -> Result<&mut Self, CellError> {
+        if self.bit_len == MAX_CELL_BITS {
+            return Err(CellError::new("cell overflow"));
+        }
+        if self.bit_len.is_multiple_of(8) {
+            self.data.push(0);
+        }
+        if bit {
+            let last = self.data.len() - 1;
+            self.data[last] |= 0x80 >> (self.bit_len % 8);
+        }
+        self.bit_len += 1;
+        Ok(self)
+    }
+
+    /// Stores `bit_len` lowest bits of `value`, most significant first.
+    pub fn store_uint(&mut self, bit_len: usize, value: u64) -> Result<&mut Self, CellError> {
+        if bit_len > 64 || (bit_len < 64 && value >> bit_len != 0) {
+            return Err(CellError::new(format!(
+                "value {value} doesn't fit into {bit_len} bits"
+            )));
+        }
+        for i in (0..bit_len).rev() {
+            self.store_bit(value >> i & 1 == 1)?;
+        }
+        Ok(self)
+    }
+
+    pub fn store_bits(&mut self, bits: &[bool]) -> Result<&mut Self, CellError> {
+        for bit in bits {
+            self.store_bit(*bit)?;
+        }
+        Ok(self)
+    }
+
+    pub fn store_bytes(&mut self, bytes: &[u8]) -> Result<&mut Self, CellError> {
+        for byte in bytes {
+            self.store_uint(8, *byte as u64)?;
+        }
+        Ok(self)
+    }
+
+    pub fn store_reference(&mut self, cell: Arc<Cell>) -> Result<&mut Self, CellError> {
+        if self.references.len() == MAX_CELL_REFS {
+            return Err(CellError::new("too many cell references"));
+        }
+        self.references.push(cell);
+        Ok(self)
+    }
+
+    pub fn build(&self) -> Result<Cell, CellError> {
+        Cell::new(
+            self.data.clone(),
+            self.bit_len,
+            self.references.clone(),
+            false,
+        )
+    }
+}
+
+pub struct CellParser<'a> {
+    cell: &'a Cell,
+    bit_pos: usize,
+    ref_pos: usize,
+}
+
+impl CellParser<'_> {
+    pub fn remaining_bits(&self) -> usize {
+        self.cell.bit_len - self.bit_pos
+    }
+
+    pub fn remaining_references(&self) -> usize {
+        self.cell.references.len() - self.ref_pos
+    }
+
+    pub fn load_bit(&mut self) -> Result<bool, CellError> {
+        if self.bit_pos == self.cell.bit_len {
+            return Err(CellError::new("cell underflow"));
+        }
+        let bit = self.cell.data[self.bit_pos / 8] & (0x80 >> (self.bit_pos % 8)) != 0;
+        self.bit_pos += 1;
+        Ok(bit)
+    }
+
+    pub fn load_uint(&mut self, bit_len: usize) -> Result<u64, CellError> {
+        if bit_len > 64 {
+            return Err(CellError::new(format!("can't load {bit_len} bits as u64")));
+        }
+        let mut value = 0u64;
+        for _ in 0..bit_len {
+            value = value << 1 | self.load_bit()? as u64;
+        }
+        Ok(value)
+    }
+
+    pub fn load_bits(&mut self, bit_len: usize) -> Result<Vec<bool>, CellError> {
+        (0..bit_len).map(|_| self.load_bit()).collect()
+    }
+
+    pub fn next_reference(&mut self) -> Result<Arc<Cell>, CellError> {
+        let reference = self
+            .cell
+            .references
+            .get(self.ref_pos)
+            .ok_or_else(|| CellError::new("no more references"))?;
+        self.ref_pos += 1;
+        Ok(reference.clone())
+    }
+}
+
+struct RawCell {
+    d1: u8,
+    data: Vec<u8>,
+    bit_len: usize,
+    references: Vec<usize>,
+}
+
+struct BocReader<'a> {
+    boc: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BocReader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], CellError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.boc.len())
+            .ok_or_else(|| CellError::new("unexpected end of BoC"))?;
+        let bytes = &self.boc[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn uint(&mut self, len: usize) -> Result<usize, CellError> {
+        Ok(self
+            .bytes(len)?
+            .iter()
+            .fold(0usize, |acc, b| acc << 8 | *b as usize))
+    }
+}
+
+fn parse_boc(boc: &[u8]) -> Result<Vec<Arc<Cell>>, CellError> {
+    let mut reader = BocReader { boc, pos: 0 };
+    if reader.uint(4)? as u32 != BOC_MAGIC {
+        return Err(CellError::new("unsupported BoC magic"));
+    }
+    let flags = reader.uint(1)?;
+    let has_idx = flags & 0x80 != 0;
+    let has_crc32c = flags & 0x40 != 0;
+    let size_bytes = flags & 0x07;
+    let offset_bytes = reader.uint(1)?;
+    if size_bytes == 0 || size_bytes > 4 || offset_bytes == 0 || offset_bytes > 8 {
+        return Err(CellError::new("invalid BoC header"));
+    }
+    let cell_count = reader.uint(size_bytes)?;
+    let root_count = reader.uint(size_bytes)?;
+    let _absent = reader.uint(size_bytes)?;
+    let _total_cells_size = reader.uint(offset_bytes)?;
+    // counts come from untrusted input, so check them before allocating:
+    // every cell takes at least 2 descriptor bytes
+    let remaining = boc.len() - reader.pos;
+    if root_count > cell_count || cell_count > remaining / 2 {
+        return Err(CellError::new("BoC cell count exceeds its size"));
+    }
+    let roots = (0..root_count)
+        .map(|_| reader.uint(size_bytes))
+        .collect::<Result<Vec<_>, _>>()?;
+    if has_idx {
+        let index_len = cell_count
+            .checked_mul(offset_bytes)
+            .ok_or_else(|| CellError::new("invalid BoC index size"))?;
+        reader.bytes(index_len)?;
+    }
+
+    let mut raw_cells = Vec::with_capacity(cell_count);
+    for _ in 0..cell_count {
+        let d1 = reader.uint(1)? as u8;
+        let d2 = reader.uint(1)?;
+        if d1 >> 5 != 0 {
+            return Err(CellError::new(
+                "cells with non-zero level are not supported",
+            ));
+        }
+        if d1 & 16 != 0 {
+            // stored hash and depth of level 0 cell
+            reader.bytes(32 + 2)?;
+        }
+        let mut data = reader.bytes(d2.div_ceil(2))?.to_vec();
+        let bit_len = if d2 % 2 == 0 {
+            d2 / 2 * 8
+        } else {
+            let last = *data
+                .last()
+                .ok_or_else(|| CellError::new("invalid cell data"))?;
+            if last == 0 {
+                return Err(CellError::new("invalid cell data padding"));
+            }
+            let padding = last.trailing_zeros() as usize + 1;
+            let len = data.len();
+            data[len - 1] &= !(1 << (padding - 1));
+            len * 8 - padding
+        };
+        let references = (0..(d1 & 7) as usize)
+            .map(|_| reader.uint(size_bytes))
+            .collect::<Result<Vec<_>, _>>()?;
+        raw_cells.push(RawCell {
+            d1,
+            data,
+            bit_len,
+            references,
+        });
+    }
+    if has_crc32c {
+        let checksum_pos = reader.pos;
+        let checksum = reader.bytes(4)?;
+        if crc32c(&boc[..checksum_pos]).to_le_bytes() != checksum {
+            return Err(CellError::new("BoC checksum mismatch"));
+        }
+    }
+
+    let mut cells: Vec<Option<Arc<Cell>>> = vec![None; cell_count];
+    for (i, raw_cell) in raw_cells.into_iter().enumerate().rev() {
+        let references = raw_cell
+            .references
+            .iter()
+            .map(|index| {
+                if *index <= i {
+                    return Err(CellError::new("BoC cells are not topologically ordered"));
+                }
+                cells
+                    .get(*index)
+                    .cloned()
+                    .flatten()
+                    .ok_or_else(|| CellError::new("invalid cell reference"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let cell = Cell::new(
+            raw_cell.data,
+            raw_cell.bit_len,
+            references,
+            raw_cell.d1 & 8 != 0,
+        )?;
+        cells[i] = Some(Arc::new(cell));
+    }
+    roots
+        .into_iter()
+        .map(|index| {
+            cells
+                .get(index)
+                .cloned()
+                .flatten()
+                .ok_or_else(|| CellError::new("invalid root index"))
+        })
+        .collect()
+}
+
+fn serialize_boc(root: &Cell) -> Vec<u8> {
+    fn visit<'a>(
+        cell: &'a Cell,
+        indices: &mut HashMap<CellHash, usize>,
+        order: &mut Vec<&'a Cell>,
+    ) {
+        if indices.contains_key(&cell.hash) {
+            return;
+        }
+        for reference in &cell.references {
+            visit(reference, indices, order);
+        }
+        indices.insert(cell.hash, order.len());
+        order.push(cell);
+    }
+
+    let mut indices = HashMap::new();
+    let mut order = vec![];
+    visit(root, &mut indices, &mut order);
+    order.reverse();
+    let cell_count = order.len();
+    let index_of = |cell: &Cell| cell_count - 1 - indices[&cell.hash];
+
+    let size_bytes = bytes_for(cell_count);
+    let mut cells_data = vec![];
+    for cell in &order {
+        cells_data.extend(descriptors(
+            cell.references.len(),
+            cell.exotic,
+            cell.bit_len,
+        ));
+        cells_data.extend(padded_data(&cell.data, cell.bit_len));
+        for reference in &cell.references {
+            cells_data.extend(&index_of(reference).to_be_bytes()[8 - size_bytes..]);
+        }
+    }
+    let offset_bytes = bytes_for(cells_data.len());
+
+    let mut boc = BOC_MAGIC.to_be_bytes().to_vec();
+    boc.push(0x40 | size_bytes as u8); // has_crc32c
+    boc.push(offset_bytes as u8);
+    boc.extend(&cell_count.to_be_bytes()[8 - size_bytes..]);
+    boc.extend(&1usize.to_be_bytes()[8 - size_bytes..]);
+    boc.extend(&0usize.to_be_bytes()[8 - size_bytes..]);
+    boc.extend(&cells_data.len().to_be_bytes()[8 - offset_bytes..]);
+    boc.extend(&0usize.to_be_bytes()[8 - size_bytes..]);
+    boc.extend(cells_data);
+    let checksum = crc32c(&boc);
+    boc.extend(checksum.to_le_bytes());
+    boc
+}
+
+fn bytes_for(value: usize) -> usize {
+    let bits = usize::BITS - value.leading_zeros();
+    (bits as usize).div_ceil(8).max(1)
+}
+
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                crc >> 1 ^ 0x82f63b78
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_hashes_cells() {
+        let empty = CellBuilder::new().build().unwrap();
+        assert_eq!(
+            empty.hash_hex(),
+            "96A296D224F285C67BEE93C30F8A309157F0DAA35DC5B87E410B78630A09CFC7"
+        );
+        assert_eq!(crc32c(b"123456789"), 0xe3069283);
+    }
+
+    #[test]
+    fn it_round_trips_boc() {
+        let leaf = Arc::new(
+            CellBuilder::new()
+                .store_uint(5, 0b10110)
+                .unwrap()
+                .build()
+                .unwrap(),
+        );
+        let mut builder = CellBuilder::new();
+        builder.store_bytes(b"root").unwrap();
+        builder.store_reference(leaf.clone()).unwrap();
+        builder.store_reference(leaf.clone()).unwrap();
+        let root = builder.build().unwrap();
+
+        let parsed = Cell::from_boc_b64(&root.to_boc_b64()).unwrap();
+        assert_eq!(*parsed, root);
+        assert_eq!(parsed.depth(), 1);
+        let mut parser = parsed.references()[1].parser();
+        assert_eq!(parser.load_uint(5).unwrap(), 0b10110);
+        assert!(parser.load_bit().is_err());
+    }
+
+    #[test]
+    fn it_parses_contract_code() {
+        let code = "te6cckECCwEAAe0AART/APSkE/S88sgLAQIBYgIDAgLMBAUCA3pgCQoD79mRDjgEit8GhpgYC42Eit8H0gGADpj+mf9qJofQB9IGpqGEAKqThdRxgamqiq44L5cCSA/SB9AGoYEGhAMGuQ/QAYEogaKCF4BFAqkGQoAn0BLGeLZmZk9qpwQQg97svvKThdcYEakuAB8YEYAmACcYEvgsIH+XhAYHCACT38FCIBuCoQCaoKAeQoAn0BLGeLAOeLZmSRZGWAiXoAegBlgGSQfIA4OmRlgWUD5f/k6DvADGRlgqxniygCfQEJ5bWJZmZkuP2AQA/jYD+gD6QPgoVBIIcFQgE1QUA8hQBPoCWM8WAc8WzMkiyMsBEvQA9ADLAMn5AHB0yMsCygfL/8nQUAjHBfLgShKhA1AkyFAE+gJYzxbMzMntVAH6QDAg1wsBwwCOH4IQ1TJ223CAEMjLBVADzxYi+gISy2rLH8s/yYBC+wCRW+IAMDUVxwXy4En6QDBZyFAE+gJYzxbMzMntVAAuUUPHBfLgSdQwAchQBPoCWM8WzMzJ7VQAfa289qJofQB9IGpqGDYY/BQAuCoQCaoKAeQoAn0BLGeLAOeLZmSRZGWAiXoAegBlgGT8gDg6ZGWBZQPl/+ToQAAfrxb2omh9AH0gamoYP6qQQFEAfwk=";
+        let cell = Cell::from_boc_b64(code).unwrap();
+        assert_eq!(cell.references().len(), 1);
+        let reparsed = Cell::from_boc_b64(&cell.to_boc_b64()).unwrap();
+        assert_eq!(reparsed.hash(), cell.hash());
+        assert!(Cell::from_boc_b64("not a boc").is_err());
+    }
+
+    #[test]
+    fn it_rejects_oversized_counts() {
+        let header = |flags: u8, count: &[u8]| {
+            let mut boc = BOC_MAGIC.to_be_bytes().to_vec();
+            boc.extend([flags, 8]);
+            for _ in 0..3 {
+                boc.extend(count);
+            }
+            boc.extend([0xff; 8]);
+            boc
+        };
+        // would allocate gigabytes if the declared counts were trusted
+        for boc in [
+            header(0x04, &[0xff; 4]),
+            header(0x04, &[0x7f, 0xff, 0xff, 0xff]),
+            header(0x84, &[0xff; 4]),
+            header(0x02, &[0x00, 0x02]),
+        ] {
+            assert_eq!(
+                Cell::from_boc(&boc),
+                Err(CellError::new("BoC cell count exceeds its size"))
+            );
+        }
+
+        let mut boc = Cell::from_boc_b64("te6cckEBAQEABQAABgAAANAJX0U=")
+            .unwrap()
+            .to_boc();
+        for i in 0..boc.len() {
+            for byte in [0x00, 0x7f, 0xff] {
+                let original = boc[i];
+                boc[i] = byte;
+                let _ = Cell::from_boc(&boc);
+                boc[i] = original;
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::error::TonlibSysError;
+use crate::tonlibjson::*;
+use crate::tvm::c_string;
+
+/// Poll timeout of the receiver thread, bounds the time needed to drop the client.
+const RECEIVE_TIMEOUT: f64 = 0.1;
+const EXTRA_PREFIX: &str = "tonlib-sys:";
+
+type Pending = Mutex<HashMap<u64, Sender<Result<Value, TonlibSysError>>>>;
+
+struct ClientPtr(*mut c_void);
+
+// tonlibjson client may be used from several threads as long as only one of them receives
+unsafe impl Send for ClientPtr {}
+unsafe impl Sync for ClientPtr {}
+
+struct Inner {
+    ptr: ClientPtr,
+    pending: Pending,
+    next_id: AtomicU64,
+    stop: AtomicBool,
+}
+
+impl Inner {
+    fn complete(&self, id: u64, result: Result<Value, TonlibSysError>) {
+        let sender = self.pending.lock().unwrap().remove(&id);
+        if let Some(sender) = sender {
+            let _ = sender.send(result);
+        }
+    }
+}
+
+/// Owned tonlibjson client with per-request deadlines.
+///
+/// Requests are tagged with `@extra` (any `@extra` set by the caller is replaced) and matched
+/// with responses on a background receiver thread. Responses arriving after the deadline or
+/// cancellation, as well as updates without `@extra`, are discarded.
+pub struct TonlibClient {
+    inner: Arc<Inner>,
+    receiver: Option<JoinHandle<()>>,
+}
+
+impl TonlibClient {
+    pub fn new() -> Result<Self, TonlibSysError> {
+        let ptr = unsafe { tonlib_client_json_create() };
+        if ptr.is_null() {
+            return Err(TonlibSysError::NullHandle {
+                function: "tonlib_client_json_create",
+            });
+        }
+        let inner = Arc::new(Inner {
+            ptr: ClientPtr(ptr),
+            pending: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(0),
+            stop: AtomicBool::new(false),
+        });
+        let receiver = {
+            let inner = inner.clone();
+            std::thread::Builder::new()
+                .name("tonlib-receive".to_string())
+                .spawn(move || receive(&inner))
+                .expect("failed to spawn tonlib receiver thread")
+        };
+        Ok(Self {
+            inner,
+            receiver: Some(receiver),
+        })
+    }
+
+    /// Sends request without waiting for the response.
+    pub fn send(&self, mut request: Value) -> Result<PendingRequest, TonlibSysError> {
+        let Some(object) = request.as_object_mut() else {
+            return Err(TonlibSysError::MalformedJson(
+                "request is not an object".to_string(),
+            ));
+        };
+        let pending = self.register();
+        object.insert("@extra".to_string(), extra(pending.id).into());
+        let request = c_string(&request.to_string(), "request")?;
+        unsafe { tonlib_client_json_send(self.inner.ptr.0, request.as_ptr()) };
+        Ok(pending)
+    }
+
+    /// Allocates request id and waits for the response with this id.
+    fn register(&self) -> PendingRequest {
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = mpsc::channel();
+        self.inner.pending.lock().unwrap().insert(id, sender);
+        PendingRequest {
+            id,
+            receiver,
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Sends request and waits for the response at most `timeout`.
+    pub fn execute(&self, request: Value, timeout: Duration) -> Result<Value, TonlibSysError> {
+        self.send(request)?.wait(timeout)
+    }
+}
+
+impl Drop for TonlibClient {
+    fn drop(&mut self) {
+        self.inner.stop.store(true, Ordering::Release);
+        if let Some(receiver) = self.receiver.take() {
+            let _ = receiver.join();
+        }
+        for (_, sender) in self.inner.pending.lock().unwrap().drain() {
+            let _ = sender.send(Err(TonlibSysError::Cancelled));
+        }
+        unsafe { tonlib_client_json_destroy(self.inner.ptr.0) }
+    }
+}
+
+/// Request sent by [`TonlibClient::send`].
+pub struct PendingRequest {
+    id: u64,
+    receiver: Receiver<Result<Value, TonlibSysError>>,
+    inner: Arc<Inner>,
+}
+
+impl PendingRequest {
+    pub fn cancel_handle(&self) -> CancelHandle {
+        CancelHandle {
+            id: self.id,
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Waits for the response. Fails with [`TonlibSysError::Timeout`] once `timeout` elapses,
+    /// or with [`TonlibSysError::Cancelled`] if the request is cancelled meanwhile.
+    pub fn wait(self, timeout: Duration) -> Result<Value, TonlibSysError> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                self.inner.pending.lock().unwrap().remove(&self.id);
+                Err(TonlibSysError::Timeout { timeout })
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(TonlibSysError::Cancelled),
+        }
+    }
+}
+
+/// Unblocks [`PendingRequest::wait`] from another thread.
+#[derive(Clone)]
+pub struct CancelHandle {
+    id: u64,
+    inner: Weak<Inner>,
+}
+
+impl CancelHandle {
+    pub fn cancel(&self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.complete(self.id, Err(TonlibSysError::Cancelled));
+        }
+    }
+}
+
+fn receive(inner: &Inner) {
+    while !inner.stop.load(Ordering::Acquire) {
+        // response is owned by tonlib and valid until the next receive
+        let response = unsafe { tonlib_client_json_receive(inner.ptr.0, RECEIVE_TIMEOUT) };
+        if response.is_null() {
+            continue;
+        }
+        let response = unsafe { CStr::from_ptr(response) }.to_string_lossy();
+        dispatch(inner, &response);
+    }
+}
+
+fn extra(id: u64) -> String {
+    format!("{EXTRA_PREFIX}{id}")
+}
+
+fn dispatch(inner: &Inner, response: &str) {
+    let Ok(response) = serde_json::from_str::<Value>(response) else {
+        return;
+    };
+    let Some(id) = response["@extra"]
+        .as_str()
+        .and_then(|extra| extra.strip_prefix(EXTRA_PREFIX))
+        .and_then(|id| id.parse().ok())
+    else {
+        return;
+    };
+    let result = match response["@type"].as_str() {
+        Some("error") => Err(TonlibSysError::Tonlib {
+            code: response["code"].as_i64().unwrap_or_default() as i32,
+            message: response["message"].as_str().unwrap_or_default().to_string(),
+        }),
+        _ => Ok(response),
+    };
+    inner.complete(id, result);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::config::KeyStoreType;
+    use crate::mock_liteserver::MockLiteServer;
+
+    const GET_MASTERCHAIN_INFO: u32 = 0x89b5e62e;
+
+    /// Client initialized against a lite-server which never answers, so `sync` blocks.
+    fn stalled_client() -> (TonlibClient, MockLiteServer) {
+        let server = MockLiteServer::start().with_silence(GET_MASTERCHAIN_INFO);
+        let client = TonlibClient::new().unwrap();
+        client
+            .execute(
+                server.config().init_request(&KeyStoreType::InMemory),
+                Duration::from_secs(10),
+            )
+            .unwrap();
+        (client, server)
+    }
+
+    #[test]
+    fn it_times_out_and_cancels() {
+        let (client, _server) = stalled_client();
+        let timeout = Duration::from_millis(50);
+        assert_eq!(
+            client.execute(json!({"@type": "sync"}), timeout),
+            Err(TonlibSysError::Timeout { timeout })
+        );
+        assert!(client.inner.pending.lock().unwrap().is_empty());
+
+        let request = client.send(json!({"@type": "sync"})).unwrap();
+        let cancel = request.cancel_handle();
+        let canceller = std::thread::spawn(move || cancel.cancel());
+        assert_eq!(
+            request.wait(Duration::from_secs(60)),
+            Err(TonlibSysError::Cancelled)
+        );
+        canceller.join().unwrap();
+        assert!(client.inner.pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_dispatches_responses() {
+        let client = TonlibClient::new().unwrap();
+        // registered without sending, so tonlib never answers these ids
+        let ok = client.register();
+        let error = client.register();
+        let response = |body: &str, id: u64| format!(r#"{{{body}, "@extra": "{}"}}"#, extra(id));
+
+        dispatch(&client.inner, r#"{"@type": "updateSyncState"}"#);
+        dispatch(
+            &client.inner,
+            &response(
+                r#""@type": "error", "code": 500, "message": "LITE_SERVER_NETWORK timeout""#,
+                error.id,
+            ),
+        );
+        dispatch(
+            &client.inner,
+            &response(r#""@type": "ton.blockIdExt""#, ok.id),
+        );
+        // late response is dropped
+        dispatch(&client.inner, &response(r#""@type": "ok""#, ok.id));
+
+        let timeout = Duration::from_secs(1);
+        assert_eq!(ok.wait(timeout).unwrap()["@type"], "ton.blockIdExt");
+        assert_eq!(
+            error.wait(timeout),
+            Err(TonlibSysError::Tonlib {
+                code: 500,
+                message: "LITE_SERVER_NETWORK timeout".to_string()
+            })
+        );
+        assert!(client.inner.pending.lock().unwrap().is_empty());
+    }
+}
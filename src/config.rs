@@ -0,0 +1,157 @@
+use std::net::{Ipv4Addr, SocketAddrV4};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::error::TonlibSysError;
+
+/// Lite-server entry of the global config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiteServerConfig {
+    /// IPv4 address as a signed big-endian integer, as in the global config.
+    pub ip: i32,
+    pub port: u16,
+    pub id: LiteServerId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiteServerId {
+    #[serde(rename = "@type")]
+    pub key_type: String,
+    /// Base64 encoded ed25519 public key.
+    pub key: String,
+}
+
+impl LiteServerConfig {
+    pub fn new(address: SocketAddrV4, key_b64: &str) -> Self {
+        Self {
+            ip: u32::from(*address.ip()) as i32,
+            port: address.port(),
+            id: LiteServerId {
+                key_type: "pub.ed25519".to_string(),
+                key: key_b64.to_string(),
+            },
+        }
+    }
+
+    pub fn address(&self) -> SocketAddrV4 {
+        SocketAddrV4::new(Ipv4Addr::from(self.ip as u32), self.port)
+    }
+}
+
+/// Global network config (`config.global`) as published at ton.org.
+///
+/// Only lite servers are typed, everything else (`dht`, `validator`, ...) is kept as is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TonlibConfig {
+    pub liteservers: Vec<LiteServerConfig>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// Where tonlib keeps private keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyStoreType {
+    InMemory,
+    Directory(PathBuf),
+}
+
+impl TonlibConfig {
+    pub fn from_json(config: &str) -> Result<Self, TonlibSysError> {
+        Ok(serde_json::from_str(config)?)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("config is always serializable")
+    }
+
+    pub fn add_liteserver(&mut self, liteserver: LiteServerConfig) {
+        self.liteservers.push(liteserver);
+    }
+
+    pub fn retain_liteservers(&mut self, filter: impl FnMut(&LiteServerConfig) -> bool) {
+        self.liteservers.retain(filter);
+    }
+
+    /// Renders `init` request for [`tonlib_client_json_send`](crate::tonlib_client_json_send).
+    pub fn init_request(&self, keystore: &KeyStoreType) -> Value {
+        let keystore_type = match keystore {
+            KeyStoreType::InMemory => json!({"@type": "keyStoreTypeInMemory"}),
+            KeyStoreType::Directory(directory) => json!({
+                "@type": "keyStoreTypeDirectory",
+                "directory": directory.to_string_lossy(),
+            }),
+        };
+        json!({
+            "@type": "init",
+            "options": {
+                "@type": "options",
+                "config": {
+                    "@type": "config",
+                    "config": self.to_json(),
+                    "blockchain_name": "",
+                    "use_callbacks_for_network": false,
+                    "ignore_cache": false,
+                },
+                "keystore_type": keystore_type,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"{
+        "@type": "config.global",
+        "dht": {"@type": "dht.config.global", "k": 6, "a": 3, "static_nodes": {"@type": "dht.nodes", "nodes": []}},
+        "liteservers": [
+            {"ip": 84478511, "port": 19949, "id": {"@type": "pub.ed25519", "key": "n4VDnSCUuSpjnCyUk9e3QOOd6o0ItSWYbTnW3Wnn8wk="}},
+            {"ip": -2018135749, "port": 53312, "id": {"@type": "pub.ed25519", "key": "aF91CuUHuuOv9rm2W5+O/4h38M3sRm40DtSdRxQhmtQ="}}
+        ],
+        "validator": {"@type": "validator.config.global", "zero_state": {"workchain": -1, "shard": -9223372036854775808, "seqno": 0}}
+    }"#;
+
+    #[test]
+    fn it_parses_global_config() {
+        let mut config = TonlibConfig::from_json(CONFIG).unwrap();
+        assert_eq!(config.liteservers.len(), 2);
+        assert_eq!(
+            config.liteservers[0].address(),
+            "5.9.10.47:19949".parse().unwrap()
+        );
+        assert_eq!(
+            config.liteservers[1].address(),
+            "135.181.177.59:53312".parse().unwrap()
+        );
+        assert_eq!(config.other["validator"]["zero_state"]["workchain"], -1);
+
+        config.retain_liteservers(|liteserver| liteserver.port == 53312);
+        config.add_liteserver(LiteServerConfig::new(
+            "127.0.0.1:4924".parse().unwrap(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+        ));
+        let reparsed = TonlibConfig::from_json(&config.to_json()).unwrap();
+        assert_eq!(reparsed, config);
+        assert_eq!(reparsed.liteservers[1].ip, 0x7f000001);
+    }
+
+    #[test]
+    fn it_renders_init_request() {
+        let config = TonlibConfig::from_json(CONFIG).unwrap();
+        let request = config.init_request(&KeyStoreType::Directory("/tmp/keystore".into()));
+        assert_eq!(request["@type"], "init");
+        assert_eq!(
+            request["options"]["keystore_type"],
+            json!({"@type": "keyStoreTypeDirectory", "directory": "/tmp/keystore"})
+        );
+        let rendered = request["options"]["config"]["config"].as_str().unwrap();
+        assert_eq!(TonlibConfig::from_json(rendered).unwrap(), config);
+        assert_eq!(
+            config.init_request(&KeyStoreType::InMemory)["options"]["keystore_type"]["@type"],
+            "keyStoreTypeInMemory"
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use serde_json::Value;
+
+use crate::error::TonlibSysError;
+use crate::gas_profiler::{parse_location, CodeLocation};
+use crate::tvm::TvmEmulator;
+
+const DEBUG_PREFIX: &str = "#DEBUG#:";
+
+/// Output of a debug primitive (`DUMPSTK`, `DUMP`, `STRDUMP`, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugEvent {
+    /// Index of the [`TraceStep`] which produced the output.
+    pub step: usize,
+    pub message: String,
+    /// Stack right before the instruction, available with `vm_log_verbosity` >= 3.
+    pub stack: Option<Vec<String>>,
+}
+
+/// Single executed instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    pub instruction: String,
+    pub location: Option<CodeLocation>,
+    pub gas_remaining: Option<u64>,
+    pub stack: Option<Vec<String>>,
+    pub debug_output: Vec<String>,
+}
+
+/// Instructions executed by TVM, restored from `vm_log`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<TraceStep>,
+    pub exit_code: Option<i32>,
+}
+
+impl Trace {
+    pub fn from_vm_log(vm_log: &str) -> Self {
+        let mut trace = Self::default();
+        let mut location = None;
+        let mut gas_remaining = None;
+        let mut stack = None;
+        for line in vm_log.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("code cell hash: ") {
+                location = parse_location(rest);
+            } else if let Some(rest) = line.strip_prefix("gas remaining: ") {
+                gas_remaining = rest.trim().parse().ok();
+            } else if let Some(rest) = line.strip_prefix("stack: ") {
+                stack = Some(parse_stack(rest));
+            } else if let Some(rest) = line.strip_prefix("execute ") {
+                trace.steps.push(TraceStep {
+                    instruction: rest.to_string(),
+                    location: location.take(),
+                    gas_remaining: gas_remaining.take(),
+                    stack: stack.take(),
+                    debug_output: vec![],
+                });
+            } else if let Some(rest) = line.strip_prefix(DEBUG_PREFIX) {
+                if let Some(step) = trace.steps.last_mut() {
+                    step.debug_output.push(rest.trim_start().to_string());
+                }
+            } else if let Some((_, code)) = line.split_once("terminating vm with exit code ") {
+                trace.exit_code = code.trim().parse().ok();
+            }
+        }
+        trace
+    }
+
+    pub fn debug_events(&self) -> Vec<DebugEvent> {
+        self.steps
+            .iter()
+            .enumerate()
+            .flat_map(|(i, step)| {
+                step.debug_output.iter().map(move |message| DebugEvent {
+                    step: i,
+                    message: message.clone(),
+                    stack: step.stack.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Splits `[ 1 CS{Cell{...} bits: 0..4; refs: 0..0} () ]` into top-level entries.
+fn parse_stack(stack: &str) -> Vec<String> {
+    let stack = stack.trim();
+    let stack = stack
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(stack);
+
+    let mut entries = vec![];
+    let mut current = String::new();
+    let mut depth = 0usize;
+    for c in stack.chars() {
+        match c {
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if c.is_whitespace() && depth == 0 {
+            if !current.is_empty() {
+                entries.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        entries.push(current);
+    }
+    entries
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Instruction mnemonic, i.e. `THROWIF` or `PUSHINT 1`.
+    Instruction(String),
+    Location(CodeLocation),
+    /// Any step producing debug output.
+    DebugOutput,
+}
+
+impl Breakpoint {
+    fn matches(&self, step: &TraceStep) -> bool {
+        match self {
+            Breakpoint::Instruction(name) => {
+                step.instruction == *name
+                    || step
+                        .instruction
+                        .split_whitespace()
+                        .next()
+                        .is_some_and(|mnemonic| mnemonic == name)
+            }
+            Breakpoint::Location(location) => step.location.as_ref() == Some(location),
+            Breakpoint::DebugOutput => !step.debug_output.is_empty(),
+        }
+    }
+}
+
+/// Replays [`Trace`] step by step.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    trace: Trace,
+    breakpoints: Vec<Breakpoint>,
+    position: Option<usize>,
+}
+
+impl Debugger {
+    pub fn new(trace: Trace) -> Self {
+        Self {
+            trace,
+            breakpoints: vec![],
+            position: None,
+        }
+    }
+
+    /// Enables debug primitives, runs get method and returns the response JSON with a debugger over its `vm_log`.
+    /// Debug mode of the emulator is restored afterwards.
+    /// Emulator should be created with `vm_log_verbosity` >= 2 to get instruction locations.
+    pub fn run_get_method(
+        emulator: &mut TvmEmulator,
+        method_id: i32,
+        stack_boc: &str,
+    ) -> Result<(Value, Self), TonlibSysError> {
+        let debug_enabled = emulator.debug_enabled();
+        emulator.set_debug_enabled(true)?;
+        let result = emulator.run_get_method(method_id, stack_boc);
+        let restored = emulator.set_debug_enabled(debug_enabled);
+        let response = match result {
+            Ok(response) => response,
+            // failed runs are the most interesting ones to debug
+            Err(TonlibSysError::VmError { response, .. }) => response,
+            Err(error) => return Err(error),
+        };
+        restored?;
+        let response = serde_json::from_str::<Value>(&response)?;
+        let vm_log = response["vm_log"].as_str().unwrap_or_default();
+        let debugger = Self::new(Trace::from_vm_log(vm_log));
+        Ok((response, debugger))
+    }
+
+    pub fn trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    pub fn current(&self) -> Option<&TraceStep> {
+        self.trace.steps.get(self.position?)
+    }
+
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Moves to the next instruction. Returns `None` once the trace is over.
+    pub fn step(&mut self) -> Option<&TraceStep> {
+        let next = self.position.map_or(0, |position| position + 1);
+        self.position = Some(next.min(self.trace.steps.len()));
+        self.current()
+    }
+
+    /// Moves to the next step matching any breakpoint. Returns `None` if there is no such step.
+    pub fn resume(&mut self) -> Option<&TraceStep> {
+        let start = self.position.map_or(0, |position| position + 1);
+        let found = self.trace.steps.iter().skip(start).position(|step| {
+            self.breakpoints
+                .iter()
+                .any(|breakpoint| breakpoint.matches(step))
+        });
+        self.position = Some(found.map_or(self.trace.steps.len(), |i| start + i));
+        self.current()
+    }
+
+    pub fn reset(&mut self) {
+        self.position = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1A1";
+
+    fn vm_log() -> String {
+        [
+            "stack: [ 5 ]".to_string(),
+            format!("code cell hash: {HASH} offset: 0"),
+            "gas remaining: 1000".to_string(),
+            "execute DUP".to_string(),
+            "stack: [ 5 5 ]".to_string(),
+            format!("code cell hash: {HASH} offset: 8"),
+            "gas remaining: 982".to_string(),
+            "execute DUMPSTK".to_string(),
+            "#DEBUG#: stack(2 values) : 5 5".to_string(),
+            "stack: [ 5 5 CS{Cell{0008} bits: 0..16; refs: 0..0} ]".to_string(),
+            format!("code cell hash: {HASH} offset: 16"),
+            "gas remaining: 956".to_string(),
+            "execute STRDUMP".to_string(),
+            "#DEBUG#: hello".to_string(),
+            "stack: [ 5 5 ]".to_string(),
+            format!("code cell hash: {HASH} offset: 24"),
+            "gas remaining: 930".to_string(),
+            "execute THROWIF 35".to_string(),
+            "handling exception code 35: unknown".to_string(),
+            "default exception handler, terminating vm with exit code 35".to_string(),
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn it_parses_trace() {
+        let trace = Trace::from_vm_log(&vm_log());
+        assert_eq!(trace.steps.len(), 4);
+        assert_eq!(trace.exit_code, Some(35));
+        assert_eq!(trace.steps[1].instruction, "DUMPSTK");
+        assert_eq!(trace.steps[1].gas_remaining, Some(982));
+        assert_eq!(
+            trace.steps[2].stack.as_deref(),
+            Some(
+                &[
+                    "5".to_string(),
+                    "5".to_string(),
+                    "CS{Cell{0008} bits: 0..16; refs: 0..0}".to_string()
+                ][..]
+            )
+        );
+    }
+
+    #[test]
+    fn it_collects_debug_events() {
+        let events = Trace::from_vm_log(&vm_log()).debug_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].step, 1);
+        assert_eq!(events[0].message, "stack(2 values) : 5 5");
+        assert_eq!(
+            events[0].stack,
+            Some(vec!["5".to_string(), "5".to_string()])
+        );
+        assert_eq!(events[1].message, "hello");
+    }
+
+    #[test]
+    fn it_steps_through_trace() {
+        let mut debugger = Debugger::new(Trace::from_vm_log(&vm_log()));
+        assert!(debugger.current().is_none());
+        assert_eq!(debugger.step().unwrap().instruction, "DUP");
+
+        debugger.add_breakpoint(Breakpoint::Instruction("THROWIF".to_string()));
+        assert_eq!(debugger.resume().unwrap().instruction, "THROWIF 35");
+        assert!(debugger.resume().is_none());
+        assert!(debugger.step().is_none());
+
+        debugger.reset();
+        debugger.clear_breakpoints();
+        debugger.add_breakpoint(Breakpoint::DebugOutput);
+        debugger.add_breakpoint(Breakpoint::Location(CodeLocation::new(HASH, 24)));
+        assert_eq!(debugger.resume().unwrap().instruction, "DUMPSTK");
+        assert_eq!(debugger.resume().unwrap().instruction, "STRDUMP");
+        assert_eq!(debugger.position(), Some(2));
+        assert_eq!(debugger.resume().unwrap().instruction, "THROWIF 35");
+    }
+
+    #[test]
+    fn it_debugs_failed_get_method() {
+        let contract = crate::fixtures::contract("wallet_v3r2");
+        let mut emulator = TvmEmulator::new(&contract.code, &contract.data, 2).unwrap();
+        let (response, debugger) = Debugger::run_get_method(
+            &mut emulator,
+            crate::fixtures::method_id("no_such_method"),
+            &crate::fixtures::empty_stack(),
+        )
+        .unwrap();
+        assert_eq!(response["vm_exit_code"], 11);
+        assert!(response["gas_used"].is_string());
+        assert!(response["stack"].is_string());
+        assert_eq!(debugger.trace().exit_code, Some(11));
+        assert!(!emulator.debug_enabled());
+    }
+}
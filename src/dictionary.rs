@@ -0,0 +1,179 @@
+use crate::cell::{Cell, CellBuilder, CellError, CellParser};
+
+/// Builds root cell of `Hashmap key_len X` from entries sorted by key.
+/// Returns `None` for an empty dictionary.
+pub(crate) fn build_hashmap<V>(
+    entries: &[(Vec<bool>, V)],
+    key_len: usize,
+    store_value: &impl Fn(&mut CellBuilder, &V) -> Result<(), CellError>,
+) -> Result<Option<Cell>, CellError> {
+    if entries.is_empty() {
+        return Ok(None);
+    }
+    if entries.iter().any(|(key, _)| key.len() != key_len) {
+        return Err(CellError::new(format!(
+            "dictionary keys must be {key_len} bits long"
+        )));
+    }
+    if entries.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+        return Err(CellError::new("dictionary keys must be sorted and unique"));
+    }
+    build_node(entries, 0, key_len, store_value).map(Some)
+}
+
+fn build_node<V>(
+    entries: &[(Vec<bool>, V)],
+    offset: usize,
+    key_len: usize,
+    store_value: &impl Fn(&mut CellBuilder, &V) -> Result<(), CellError>,
+) -> Result<Cell, CellError> {
+    let max_len = key_len - offset;
+    let first = &entries[0].0[offset..];
+    let last = &entries[entries.len() - 1].0[offset..];
+    // keys are sorted, so the common prefix of all keys is the one of the first and the last
+    let label_len = first.iter().zip(last).take_while(|(a, b)| a == b).count();
+
+    let mut builder = CellBuilder::new();
+    store_label(&mut builder, &first[..label_len], max_len)?;
+    if label_len == max_len {
+        store_value(&mut builder, &entries[0].1)?;
+    } else {
+        let fork = offset + label_len;
+        let split = entries.partition_point(|(key, _)| !key[fork]);
+        for branch in [&entries[..split], &entries[split..]] {
+            let child = build_node(branch, fork + 1, key_len, store_value)?;
+            builder.store_reference(child.into())?;
+        }
+    }
+    builder.build()
+}
+
+/// Stores `HmLabel` choosing the shortest of `hml_short`, `hml_long` and `hml_same` like TON does.
+fn store_label(builder: &mut CellBuilder, label: &[bool], max_len: usize) -> Result<(), CellError> {
+    let len = label.len();
+    let len_bits = (usize::BITS - max_len.leading_zeros()) as usize;
+    let same = label.windows(2).all(|pair| pair[0] == pair[1]);
+    if len > 1 && same && len_bits < 2 * len - 1 {
+        builder.store_uint(2, 0b11)?;
+        builder.store_bit(label[0])?;
+        builder.store_uint(len_bits, len as u64)?;
+    } else if len_bits < len {
+        builder.store_uint(2, 0b10)?;
+        builder.store_uint(len_bits, len as u64)?;
+        builder.store_bits(label)?;
+    } else {
+        builder.store_bit(false)?;
+        for _ in 0..len {
+            builder.store_bit(true)?;
+        }
+        builder.store_bit(false)?;
+        builder.store_bits(label)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1))
+        .collect()
+}
+
+/// Reads all entries of `Hashmap key_len X` with root `root`.
+pub(crate) fn parse_hashmap<V>(
+    root: &Cell,
+    key_len: usize,
+    load_value: &impl Fn(&mut CellParser) -> Result<V, CellError>,
+) -> Result<Vec<(Vec<bool>, V)>, CellError> {
+    let mut entries = vec![];
+    parse_node(root, vec![], key_len, load_value, &mut entries)?;
+    Ok(entries)
+}
+
+fn parse_node<V>(
+    cell: &Cell,
+    mut prefix: Vec<bool>,
+    key_len: usize,
+    load_value: &impl Fn(&mut CellParser) -> Result<V, CellError>,
+    entries: &mut Vec<(Vec<bool>, V)>,
+) -> Result<(), CellError> {
+    let mut parser = cell.parser();
+    let max_len = key_len - prefix.len();
+    let label = load_label(&mut parser, max_len)?;
+    prefix.extend(label);
+    if prefix.len() == key_len {
+        let value = load_value(&mut parser)?;
+        entries.push((prefix, value));
+        return Ok(());
+    }
+    for bit in [false, true] {
+        let child = parser.next_reference()?;
+        let mut child_prefix = prefix.clone();
+        child_prefix.push(bit);
+        parse_node(&child, child_prefix, key_len, load_value, entries)?;
+    }
+    Ok(())
+}
+
+fn load_label(parser: &mut CellParser, max_len: usize) -> Result<Vec<bool>, CellError> {
+    let len_bits = (usize::BITS - max_len.leading_zeros()) as usize;
+    let label = if !parser.load_bit()? {
+        let mut len = 0;
+        while parser.load_bit()? {
+            len += 1;
+        }
+        parser.load_bits(len)?
+    } else if !parser.load_bit()? {
+        let len = parser.load_uint(len_bits)? as usize;
+        parser.load_bits(len)?
+    } else {
+        let bit = parser.load_bit()?;
+        let len = parser.load_uint(len_bits)? as usize;
+        vec![bit; len]
+    };
+    if label.len() > max_len {
+        return Err(CellError::new("dictionary label is too long"));
+    }
+    Ok(label)
+}
+
+pub(crate) fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, bit)| byte | (*bit as u8) << (7 - i))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_hashmap() {
+        let entries: Vec<(Vec<bool>, u64)> = [0u8, 1, 2, 0x80, 0xff]
+            .iter()
+            .map(|key| (bytes_to_bits(&[*key]), *key as u64 * 10))
+            .collect();
+        let store =
+            |builder: &mut CellBuilder, value: &u64| builder.store_uint(16, *value).map(|_| ());
+        let root = build_hashmap(&entries, 8, &store).unwrap().unwrap();
+        let parsed = parse_hashmap(&root, 8, &|parser| parser.load_uint(16)).unwrap();
+        assert_eq!(parsed, entries);
+        assert_eq!(bits_to_bytes(&parsed[3].0), vec![0x80]);
+    }
+
+    #[test]
+    fn it_uses_same_label_for_single_entry() {
+        let entries = vec![(vec![true; 256], ())];
+        let root = build_hashmap(&entries, 256, &|_, _| Ok(()))
+            .unwrap()
+            .unwrap();
+        // hml_same$11 v:1 n:256 in 9 bits
+        assert_eq!(root.bit_len(), 2 + 1 + 9);
+        assert_eq!(parse_hashmap(&root, 256, &|_| Ok(())).unwrap(), entries);
+    }
+}
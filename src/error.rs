@@ -0,0 +1,186 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::cell::CellError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TonlibSysError {
+    /// Native constructor returned null.
+    NullHandle {
+        function: &'static str,
+    },
+    /// Native function returned null instead of JSON response.
+    NullResponse {
+        function: &'static str,
+    },
+    /// Native setter returned `false`.
+    SetterRejected {
+        setter: &'static str,
+    },
+    /// String argument contains NUL byte and can't be passed to native code.
+    InteriorNul {
+        argument: &'static str,
+    },
+    /// Bad base64 or malformed BoC.
+    InvalidBoc(CellError),
+    /// Response with `"success": false`.
+    Emulator {
+        error: String,
+    },
+    /// TVM terminated with exit code other than 0 or 1.
+    /// `response` is the complete JSON response, including `gas_used`, `stack` and `vm_log`.
+    VmError {
+        exit_code: i32,
+        response: String,
+    },
+    /// External message was not accepted by the contract.
+    /// `response` is the complete JSON response.
+    ExternalNotAccepted {
+        exit_code: Option<i32>,
+        response: String,
+    },
+    MalformedJson(String),
+    /// Error returned by tonlib for a JSON request.
+    Tonlib {
+        code: i32,
+        message: String,
+    },
+    Io(String),
+    /// No response to tonlib request within the deadline.
+    Timeout {
+        timeout: Duration,
+    },
+    /// Tonlib request was cancelled or the client was dropped.
+    Cancelled,
+}
+
+impl fmt::Display for TonlibSysError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TonlibSysError::NullHandle { function } => write!(f, "{function} returned null"),
+            TonlibSysError::NullResponse { function } => {
+                write!(f, "{function} returned null response")
+            }
+            TonlibSysError::SetterRejected { setter } => write!(f, "{setter} failed"),
+            TonlibSysError::InteriorNul { argument } => {
+                write!(f, "{argument} contains NUL byte")
+            }
+            TonlibSysError::InvalidBoc(error) => write!(f, "invalid BoC: {error}"),
+            TonlibSysError::Emulator { error } => write!(f, "emulator error: {error}"),
+            TonlibSysError::VmError { exit_code, .. } => {
+                write!(f, "TVM terminated with exit code {exit_code}")
+            }
+            TonlibSysError::ExternalNotAccepted { exit_code, .. } => match exit_code {
+                Some(exit_code) => {
+                    write!(f, "external message not accepted, exit code {exit_code}")
+                }
+                None => write!(f, "external message not accepted"),
+            },
+            TonlibSysError::MalformedJson(error) => write!(f, "malformed JSON response: {error}"),
+            TonlibSysError::Tonlib { code, message } => {
+                write!(f, "tonlib error {code}: {message}")
+            }
+            TonlibSysError::Io(error) => write!(f, "I/O error: {error}"),
+            TonlibSysError::Timeout { timeout } => {
+                write!(f, "no response from tonlib within {timeout:?}")
+            }
+            TonlibSysError::Cancelled => write!(f, "tonlib request cancelled"),
+        }
+    }
+}
+
+impl TonlibSysError {
+    /// JSON response of an emulator call which completed, but is reported as an error
+    /// because of its outcome ([`TonlibSysError::VmError`], [`TonlibSysError::ExternalNotAccepted`]).
+    pub fn response(&self) -> Option<&str> {
+        match self {
+            TonlibSysError::VmError { response, .. }
+            | TonlibSysError::ExternalNotAccepted { response, .. } => Some(response),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for TonlibSysError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TonlibSysError::InvalidBoc(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<CellError> for TonlibSysError {
+    fn from(error: CellError) -> Self {
+        TonlibSysError::InvalidBoc(error)
+    }
+}
+
+impl From<std::io::Error> for TonlibSysError {
+    fn from(error: std::io::Error) -> Self {
+        TonlibSysError::Io(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for TonlibSysError {
+    fn from(error: serde_json::Error) -> Self {
+        TonlibSysError::MalformedJson(error.to_string())
+    }
+}
+
+/// Maps emulator JSON response with `"success": false` to an error.
+pub(crate) fn check_response(response: &str) -> Result<Value, TonlibSysError> {
+    let json: Value = serde_json::from_str(response)?;
+    if json["success"].as_bool() == Some(true) {
+        return Ok(json);
+    }
+    if json["external_not_accepted"].as_bool() == Some(true) {
+        return Err(TonlibSysError::ExternalNotAccepted {
+            exit_code: json["vm_exit_code"].as_i64().map(|code| code as i32),
+            response: response.to_string(),
+        });
+    }
+    match json["error"].as_str() {
+        Some(error) => Err(TonlibSysError::Emulator {
+            error: error.to_string(),
+        }),
+        None => Err(TonlibSysError::MalformedJson(
+            "response has neither success nor error".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_maps_error_responses() {
+        let response = r#"{"success": false, "error": "Can't deserialize message boc"}"#;
+        assert_eq!(
+            check_response(response),
+            Err(TonlibSysError::Emulator {
+                error: "Can't deserialize message boc".to_string()
+            })
+        );
+
+        let response = r#"{"success": false, "error": "External message not accepted by smart contract", "external_not_accepted": true, "vm_exit_code": 33, "vm_log": "..."}"#;
+        let error = check_response(response).unwrap_err();
+        assert_eq!(
+            error,
+            TonlibSysError::ExternalNotAccepted {
+                exit_code: Some(33),
+                response: response.to_string()
+            }
+        );
+        assert_eq!(error.response(), Some(response));
+
+        assert!(matches!(
+            check_response("{"),
+            Err(TonlibSysError::MalformedJson(_))
+        ));
+        assert!(check_response(r#"{"success": true, "vm_exit_code": 0}"#).is_ok());
+    }
+}
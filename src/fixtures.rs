@@ -0,0 +1,213 @@
+//! Loader for the fixture corpus in `tests/fixtures`.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::cell::{Cell, CellBuilder};
+use crate::snapshot::{Scenario, ScenarioKind};
+
+pub(crate) fn fixture_path(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(path)
+}
+
+/// Reads fixture file without the trailing newline.
+pub(crate) fn read_fixture(path: &str) -> String {
+    let path = fixture_path(path);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()))
+        .trim_end()
+        .to_string()
+}
+
+pub(crate) struct Contract {
+    pub code: String,
+    pub data: String,
+}
+
+pub(crate) fn contract(name: &str) -> Contract {
+    Contract {
+        code: read_fixture(&format!("contracts/{name}/code.boc.b64")),
+        data: read_fixture(&format!("contracts/{name}/data.boc.b64")),
+    }
+}
+
+/// Distinct small cell standing in for a library code cell.
+pub(crate) fn library(value: u64) -> Arc<Cell> {
+    Arc::new(
+        CellBuilder::new()
+            .store_uint(32, value)
+            .unwrap()
+            .build()
+            .unwrap(),
+    )
+}
+
+/// Library reference cell (exotic, type 2) to `library`, resolved by TVM when loaded.
+pub(crate) fn library_reference(library: &Cell) -> Arc<Cell> {
+    let mut data = vec![2];
+    data.extend_from_slice(&library.hash());
+    Arc::new(Cell::new(data, 8 + 256, vec![], true).unwrap())
+}
+
+pub(crate) fn mainnet_config() -> String {
+    read_fixture("config/mainnet.boc.b64")
+}
+
+pub(crate) fn empty_stack() -> String {
+    read_fixture("stack/empty.boc.b64")
+}
+
+/// Emulator call described in `scenarios.json`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ScenarioFixture {
+    /// Get method of a contract from `contracts`.
+    GetMethod {
+        name: String,
+        contract: String,
+        method: String,
+    },
+    /// Transaction with the mainnet config, BoCs are fixture paths.
+    Transaction {
+        name: String,
+        shard_account: String,
+        message: String,
+        unixtime: u32,
+        lt: u64,
+    },
+}
+
+impl ScenarioFixture {
+    /// Get methods run with the empty stack and without `c7` set.
+    fn into_scenario(self) -> Scenario {
+        match self {
+            ScenarioFixture::GetMethod {
+                name,
+                contract: contract_name,
+                method,
+            } => {
+                let contract = contract(&contract_name);
+                Scenario {
+                    name,
+                    kind: ScenarioKind::GetMethod {
+                        code_boc: contract.code,
+                        data_boc: contract.data,
+                        method_id: method_id(&method),
+                        stack_boc: empty_stack(),
+                        config_boc: None,
+                        address: String::new(),
+                        unixtime: 0,
+                        balance: 0,
+                    },
+                }
+            }
+            ScenarioFixture::Transaction {
+                name,
+                shard_account,
+                message,
+                unixtime,
+                lt,
+            } => Scenario {
+                name,
+                kind: ScenarioKind::Transaction {
+                    config_boc: mainnet_config(),
+                    shard_account_boc: read_fixture(&shard_account),
+                    message_boc: read_fixture(&message),
+                    unixtime,
+                    lt,
+                    rand_seed_hex: "0".repeat(64),
+                },
+            },
+        }
+    }
+}
+
+/// Scenarios of the corpus, snapshots are in `tests/fixtures/expected`.
+pub(crate) fn scenarios() -> Vec<Scenario> {
+    let fixtures: Vec<ScenarioFixture> =
+        serde_json::from_str(&read_fixture("scenarios.json")).unwrap();
+    fixtures
+        .into_iter()
+        .map(ScenarioFixture::into_scenario)
+        .collect()
+}
+
+/// Get method id as computed by FunC: CRC16/XMODEM of the name with bit 16 set.
+pub(crate) fn method_id(name: &str) -> i32 {
+    let mut crc = 0u16;
+    for byte in name.bytes() {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    (crc as i32) | 0x10000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::SnapshotRunner;
+    use crate::transaction::TransactionEmulator;
+
+    #[test]
+    fn it_loads_fixtures() {
+        for (name, code_hash) in [
+            (
+                "wallet_v3r2",
+                "84DAFA449F98A6987789BA232358072BC0F76DC4524002A5D0918B9A75D2D599",
+            ),
+            (
+                "wallet_v4r2",
+                "FEB5FF6820E2FF0D9483E7E0D62C817D846789FB4AE580C878866D959DABD5C0",
+            ),
+            (
+                "wallet_v5r1",
+                "20834B7B72B112147E1B2FB457B84E74D1A30F04F737D4F62A668E9552D2B72F",
+            ),
+        ] {
+            let wallet = contract(name);
+            assert_eq!(
+                Cell::from_boc_b64(&wallet.code).unwrap().hash_hex(),
+                code_hash
+            );
+        }
+        for name in [
+            "jetton_master",
+            "jetton_wallet",
+            "stonfi_pool",
+            "nft_item",
+            "nft_collection",
+        ] {
+            let contract = contract(name);
+            Cell::from_boc_b64(&contract.code).unwrap();
+            Cell::from_boc_b64(&contract.data).unwrap();
+        }
+        Cell::from_boc_b64(&mainnet_config()).unwrap();
+        assert!(scenarios()
+            .iter()
+            .any(|scenario| matches!(scenario.kind, ScenarioKind::Transaction { .. })));
+
+        assert_eq!(method_id("seqno"), 85143);
+        assert_eq!(method_id("get_jetton_data"), 106029);
+        assert_eq!(method_id("get_nft_data"), 102351);
+    }
+
+    #[test]
+    fn it_creates_transaction_emulator_from_config() {
+        TransactionEmulator::new(&mainnet_config(), 1).unwrap();
+    }
+
+    #[test]
+    fn it_matches_golden_files() {
+        SnapshotRunner::new(fixture_path("expected")).assert_all(&scenarios());
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use serde::Deserialize;
+
+/// Position of a TVM instruction: representation hash of the code cell and bit offset inside it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CodeLocation {
+    pub cell_hash: String,
+    pub offset: u32,
+}
+
+impl CodeLocation {
+    pub fn new(cell_hash: &str, offset: u32) -> Self {
+        Self {
+            cell_hash: cell_hash.to_ascii_uppercase(),
+            offset,
+        }
+    }
+}
+
+/// Source position produced by FunC/Tolk compiler for a code location.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u32,
+    pub function: String,
+}
+
+#[derive(Deserialize)]
+struct DebugMapEntry {
+    cell_hash: String,
+    offset: u32,
+    #[serde(flatten)]
+    source: SourceLocation,
+}
+
+/// Mapping of code locations to source locations.
+///
+/// JSON form is a list of entries:
+/// `[{"cell_hash": "...", "offset": 0, "file": "wallet.fc", "line": 12, "function": "recv_internal"}]`
+#[derive(Debug, Clone, Default)]
+pub struct DebugMap {
+    locations: HashMap<CodeLocation, SourceLocation>,
+}
+
+impl DebugMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let entries: Vec<DebugMapEntry> = serde_json::from_str(json)?;
+        let mut debug_map = Self::new();
+        for entry in entries {
+            debug_map.insert(
+                CodeLocation::new(&entry.cell_hash, entry.offset),
+                entry.source,
+            );
+        }
+        Ok(debug_map)
+    }
+
+    pub fn insert(&mut self, location: CodeLocation, source: SourceLocation) {
+        self.locations.insert(location, source);
+    }
+
+    pub fn get(&self, location: &CodeLocation) -> Option<&SourceLocation> {
+        self.locations.get(location)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasEntry {
+    pub gas: u64,
+    pub hits: u64,
+}
+
+impl GasEntry {
+    fn add(&mut self, other: GasEntry) {
+        self.gas += other.gas;
+        self.hits += other.hits;
+    }
+}
+
+struct Step {
+    location: Option<CodeLocation>,
+    gas_remaining: Option<u64>,
+}
+
+/// Gas usage aggregated per code location.
+///
+/// Built from `vm_log` of emulator created with `vm_log_verbosity` >= 2, which prints
+/// `code cell hash: <hash> offset: <offset>` before every instruction.
+/// Gas is attributed from `gas remaining: <gas>` lines printed with verbosity >= 3;
+/// with verbosity 2 only instruction hits are counted.
+#[derive(Debug, Clone, Default)]
+pub struct GasProfile {
+    by_location: BTreeMap<CodeLocation, GasEntry>,
+    unknown: GasEntry,
+}
+
+impl GasProfile {
+    /// Parses `vm_log`. `gas_used` from the emulator response is needed to attribute gas
+    /// of the last executed instruction, which has no following `gas remaining` line.
+    pub fn from_vm_log(vm_log: &str, gas_used: Option<u64>) -> Self {
+        let steps = parse_steps(vm_log);
+        let initial_gas = steps.iter().find_map(|step| step.gas_remaining);
+
+        let mut profile = Self::default();
+        for (i, step) in steps.iter().enumerate() {
+            let gas = match (step.gas_remaining, steps.get(i + 1)) {
+                (Some(before), Some(next)) => next
+                    .gas_remaining
+                    .map(|after| before.saturating_sub(after))
+                    .unwrap_or_default(),
+                (Some(before), None) => match (gas_used, initial_gas) {
+                    (Some(gas_used), Some(initial_gas)) => {
+                        gas_used.saturating_sub(initial_gas.saturating_sub(before))
+                    }
+                    _ => 0,
+                },
+                (None, _) => 0,
+            };
+            let entry = match &step.location {
+                Some(location) => profile.by_location.entry(location.clone()).or_default(),
+                None => &mut profile.unknown,
+            };
+            entry.add(GasEntry { gas, hits: 1 });
+        }
+        profile
+    }
+
+    pub fn total(&self) -> GasEntry {
+        let mut total = self.unknown;
+        self.by_location
+            .values()
+            .for_each(|entry| total.add(*entry));
+        total
+    }
+
+    pub fn by_location(&self) -> &BTreeMap<CodeLocation, GasEntry> {
+        &self.by_location
+    }
+
+    /// Gas of instructions executed without known location (i.e. verbosity < 2).
+    pub fn unknown(&self) -> GasEntry {
+        self.unknown
+    }
+
+    pub fn by_function(&self, debug_map: &DebugMap) -> BTreeMap<String, GasEntry> {
+        let mut result = BTreeMap::<String, GasEntry>::new();
+        for (location, entry) in &self.by_location {
+            let function = debug_map
+                .get(location)
+                .map(|source| source.function.clone())
+                .unwrap_or_else(|| unmapped_frame(location));
+            result.entry(function).or_default().add(*entry);
+        }
+        result
+    }
+
+    pub fn by_line(&self, debug_map: &DebugMap) -> BTreeMap<SourceLocation, GasEntry> {
+        let mut result = BTreeMap::<SourceLocation, GasEntry>::new();
+        for (location, entry) in &self.by_location {
+            if let Some(source) = debug_map.get(location) {
+                result.entry(source.clone()).or_default().add(*entry);
+            }
+        }
+        result
+    }
+
+    /// Table of `gas hits location` rows sorted by gas, most expensive first.
+    pub fn flat_table(&self, debug_map: Option<&DebugMap>) -> String {
+        let mut rows: Vec<(String, GasEntry)> = match debug_map {
+            Some(debug_map) => {
+                let mut rows = BTreeMap::<String, GasEntry>::new();
+                for (location, entry) in &self.by_location {
+                    let name = match debug_map.get(location) {
+                        Some(source) => {
+                            format!("{} ({}:{})", source.function, source.file, source.line)
+                        }
+                        None => unmapped_frame(location),
+                    };
+                    rows.entry(name).or_default().add(*entry);
+                }
+                rows.into_iter().collect()
+            }
+            None => self
+                .by_location
+                .iter()
+                .map(|(location, entry)| (unmapped_frame(location), *entry))
+                .collect(),
+        };
+        rows.sort_by(|(name_a, a), (name_b, b)| b.gas.cmp(&a.gas).then(name_a.cmp(name_b)));
+
+        let mut table = format!("{:>10} {:>8} location\n", "gas", "hits");
+        for (name, entry) in rows {
+            let _ = writeln!(table, "{:>10} {:>8} {name}", entry.gas, entry.hits);
+        }
+        table
+    }
+
+    /// Collapsed stacks (`frame;frame value` per line) consumable by flamegraph tools.
+    /// Frames are `function;file:line` when location is mapped and `cell_hash;offset` otherwise.
+    pub fn collapsed_stacks(&self, debug_map: Option<&DebugMap>) -> String {
+        let mut stacks = BTreeMap::<String, u64>::new();
+        for (location, entry) in &self.by_location {
+            let stack = match debug_map.and_then(|debug_map| debug_map.get(location)) {
+                Some(source) => format!("{};{}:{}", source.function, source.file, source.line),
+                None => format!("{};{}", location.cell_hash, location.offset),
+            };
+            *stacks.entry(stack).or_default() += entry.gas;
+        }
+
+        let mut collapsed = String::new();
+        for (stack, gas) in stacks.into_iter().filter(|(_, gas)| *gas > 0) {
+            let _ = writeln!(collapsed, "{stack} {gas}");
+        }
+        collapsed
+    }
+}
+
+fn unmapped_frame(location: &CodeLocation) -> String {
+    format!("{}:{}", location.cell_hash, location.offset)
+}
+
+fn parse_steps(vm_log: &str) -> Vec<Step> {
+    let mut steps = vec![];
+    let mut location = None;
+    let mut gas_remaining = None;
+    for line in vm_log.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("code cell hash: ") {
+            location = parse_location(rest);
+        } else if let Some(rest) = line.strip_prefix("gas remaining: ") {
+            gas_remaining = rest.trim().parse().ok();
+        } else if line.starts_with("execute ") {
+            steps.push(Step {
+                location: location.take(),
+                gas_remaining: gas_remaining.take(),
+            });
+        }
+    }
+    steps
+}
+
+pub(crate) fn parse_location(line: &str) -> Option<CodeLocation> {
+    let (hash, offset) = line.split_once(" offset: ")?;
+    Some(CodeLocation::new(hash.trim(), offset.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH_A: &str = "a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1";
+    const HASH_B: &str = "B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2B2";
+
+    fn vm_log() -> String {
+        [
+            format!("code cell hash: {HASH_A} offset: 0"),
+            "gas remaining: 1000".to_string(),
+            "execute SETCP 0".to_string(),
+            format!("code cell hash: {HASH_A} offset: 8"),
+            "gas remaining: 974".to_string(),
+            "execute DICTPUSHCONST 19 (xC_,1)".to_string(),
+            format!("code cell hash: {HASH_B} offset: 0"),
+            "gas remaining: 940".to_string(),
+            "execute PUSHINT 1".to_string(),
+            format!("code cell hash: {HASH_A} offset: 0"),
+            "gas remaining: 922".to_string(),
+            "execute SETCP 0".to_string(),
+        ]
+        .join("\n")
+    }
+
+    fn debug_map() -> DebugMap {
+        let json = format!(
+            r#"[
+                {{"cell_hash": "{HASH_A}", "offset": 0, "file": "main.fc", "line": 1, "function": "main"}},
+                {{"cell_hash": "{HASH_A}", "offset": 8, "file": "main.fc", "line": 2, "function": "main"}},
+                {{"cell_hash": "{HASH_B}", "offset": 0, "file": "lib.fc", "line": 10, "function": "one"}}
+            ]"#
+        );
+        DebugMap::from_json(&json).unwrap()
+    }
+
+    #[test]
+    fn it_aggregates_gas_by_location() {
+        let profile = GasProfile::from_vm_log(&vm_log(), Some(104));
+        let entry = profile.by_location()[&CodeLocation::new(HASH_A, 0)];
+        assert_eq!(
+            entry,
+            GasEntry {
+                gas: 26 + 26,
+                hits: 2
+            }
+        );
+        let entry = profile.by_location()[&CodeLocation::new(HASH_B, 0)];
+        assert_eq!(entry, GasEntry { gas: 18, hits: 1 });
+        assert_eq!(profile.total(), GasEntry { gas: 104, hits: 4 });
+    }
+
+    #[test]
+    fn it_counts_hits_without_gas_lines() {
+        let vm_log =
+            format!("code cell hash: {HASH_A} offset: 0\nexecute SETCP 0\nexecute implicit RET");
+        let profile = GasProfile::from_vm_log(&vm_log, Some(50));
+        assert_eq!(profile.total(), GasEntry { gas: 0, hits: 2 });
+        assert_eq!(profile.unknown(), GasEntry { gas: 0, hits: 1 });
+    }
+
+    #[test]
+    fn it_reports_gas_by_function_and_line() {
+        let profile = GasProfile::from_vm_log(&vm_log(), Some(104));
+        let debug_map = debug_map();
+        let by_function = profile.by_function(&debug_map);
+        assert_eq!(by_function["main"].gas, 86);
+        assert_eq!(by_function["one"].gas, 18);
+
+        let by_line = profile.by_line(&debug_map);
+        let line = SourceLocation {
+            file: "main.fc".to_string(),
+            line: 2,
+            function: "main".to_string(),
+        };
+        assert_eq!(by_line[&line], GasEntry { gas: 34, hits: 1 });
+    }
+
+    #[test]
+    fn it_renders_flat_table_and_collapsed_stacks() {
+        let profile = GasProfile::from_vm_log(&vm_log(), Some(104));
+        let debug_map = debug_map();
+
+        let table = profile.flat_table(Some(&debug_map));
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert!(rows[1].ends_with("main (main.fc:1)"));
+        assert!(rows[1].trim_start().starts_with("52 "));
+
+        let collapsed = profile.collapsed_stacks(Some(&debug_map));
+        assert_eq!(
+            collapsed,
+            "main;main.fc:1 52\nmain;main.fc:2 34\none;lib.fc:10 18\n"
+        );
+        let collapsed = profile.collapsed_stacks(None);
+        assert!(collapsed.contains(&format!("{};0 52", HASH_A.to_ascii_uppercase())));
+    }
+}
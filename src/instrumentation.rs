@@ -0,0 +1,277 @@
+//! Optional `tracing` spans and `metrics` around emulator calls.
+//!
+//! Span `emulator_call` has fields `operation`, `method_id`, `address`, `gas_used`,
+//! `exit_code` and `elapsed_time` (as reported by the emulator, in seconds).
+//! For transactions gas and exit code are taken from the compute phase of the resulting
+//! transaction.
+//! Metrics:
+//! * `tonlib_sys_emulator_calls_total` counter labeled with `operation` and `status`
+//! * `tonlib_sys_emulator_duration_seconds` histogram of wall time labeled with `operation`
+//! * `tonlib_sys_emulator_gas_used` histogram labeled with `operation`
+
+use crate::error::TonlibSysError;
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+use crate::transaction::ComputePhase;
+
+pub(crate) struct CallInfo<'a> {
+    pub operation: &'static str,
+    pub method_id: Option<i32>,
+    /// Resolved lazily, only if the `emulator_call` span is enabled.
+    pub address: &'a dyn Fn() -> Option<String>,
+}
+
+#[cfg(not(any(feature = "tracing", feature = "metrics")))]
+pub(crate) fn observe(
+    info: CallInfo,
+    call: impl FnOnce() -> Result<String, TonlibSysError>,
+) -> Result<String, TonlibSysError> {
+    let _ = (info.operation, info.method_id, info.address);
+    call()
+}
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+pub(crate) fn observe(
+    info: CallInfo,
+    call: impl FnOnce() -> Result<String, TonlibSysError>,
+) -> Result<String, TonlibSysError> {
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "emulator_call",
+        operation = info.operation,
+        method_id = info.method_id,
+        address = tracing::field::Empty,
+        gas_used = tracing::field::Empty,
+        exit_code = tracing::field::Empty,
+        elapsed_time = tracing::field::Empty,
+    );
+    #[cfg(feature = "tracing")]
+    let span_enabled = !span.is_disabled();
+    #[cfg(feature = "tracing")]
+    if span_enabled {
+        if let Some(address) = (info.address)() {
+            span.record("address", address);
+        }
+    }
+    #[cfg(feature = "tracing")]
+    let _guard = span.enter();
+
+    #[cfg(not(feature = "tracing"))]
+    let span_enabled = false;
+    #[cfg(not(feature = "tracing"))]
+    let _ = (info.method_id, info.address);
+
+    let started_at = std::time::Instant::now();
+    let result = call();
+    let duration = started_at.elapsed();
+
+    // parsing the response is the costly part, skip it if nothing consumes the summary
+    let summary = if span_enabled || cfg!(feature = "metrics") {
+        match &result {
+            Ok(response) => ResponseSummary::parse(response),
+            Err(error) => error
+                .response()
+                .map(ResponseSummary::parse)
+                .unwrap_or_default(),
+        }
+    } else {
+        ResponseSummary::default()
+    };
+
+    #[cfg(feature = "tracing")]
+    {
+        if let Some(gas_used) = summary.gas_used {
+            span.record("gas_used", gas_used);
+        }
+        if let Some(exit_code) = summary.exit_code {
+            span.record("exit_code", exit_code);
+        }
+        if let Some(elapsed_time) = summary.elapsed_time {
+            span.record("elapsed_time", elapsed_time);
+        }
+        if let Err(error) = &result {
+            tracing::debug!(%error, "emulator call failed");
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    {
+        let status = if result.is_ok() { "ok" } else { "error" };
+        metrics::counter!(
+            "tonlib_sys_emulator_calls_total",
+            "operation" => info.operation,
+            "status" => status
+        )
+        .increment(1);
+        metrics::histogram!(
+            "tonlib_sys_emulator_duration_seconds",
+            "operation" => info.operation
+        )
+        .record(duration.as_secs_f64());
+        if let Some(gas_used) = summary.gas_used {
+            metrics::histogram!("tonlib_sys_emulator_gas_used", "operation" => info.operation)
+                .record(gas_used as f64);
+        }
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    let _ = (duration, &summary);
+    result
+}
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+#[derive(Debug, Default, PartialEq)]
+struct ResponseSummary {
+    gas_used: Option<u64>,
+    exit_code: Option<i64>,
+    elapsed_time: Option<f64>,
+}
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+impl ResponseSummary {
+    fn parse(response: &str) -> Self {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(response) else {
+            return Self::default();
+        };
+        // gas_used is a string in some emulator versions
+        let gas_used = json["gas_used"]
+            .as_u64()
+            .or_else(|| json["gas_used"].as_str().and_then(|gas| gas.parse().ok()));
+        let mut summary = Self {
+            gas_used,
+            exit_code: json["vm_exit_code"].as_i64(),
+            elapsed_time: json["elapsed_time"].as_f64(),
+        };
+        // transaction emulator reports them only inside the transaction
+        if let Some(transaction) = json["transaction"].as_str() {
+            if let Ok(Some(compute_phase)) = ComputePhase::from_transaction_boc(transaction) {
+                summary.gas_used.get_or_insert(compute_phase.gas_used);
+                summary
+                    .exit_code
+                    .get_or_insert(compute_phase.exit_code as i64);
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(all(test, any(feature = "tracing", feature = "metrics")))]
+mod tests {
+    use super::*;
+
+    /// Successful `transaction_emulator_emulate_transaction` response.
+    fn transaction_response() -> String {
+        serde_json::json!({
+            "success": true,
+            "transaction": crate::fixtures::read_fixture("transactions/mainnet_ordinary.boc.b64"),
+            "shard_account": crate::fixtures::empty_stack(),
+            "vm_log": "",
+            "actions": null,
+            "elapsed_time": 0.004,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn it_summarizes_responses() {
+        let summary = ResponseSummary::parse(
+            r#"{"success": true, "vm_exit_code": 0, "gas_used": "1212", "elapsed_time": 0.02}"#,
+        );
+        assert_eq!(
+            summary,
+            ResponseSummary {
+                gas_used: Some(1212),
+                exit_code: Some(0),
+                elapsed_time: Some(0.02),
+            }
+        );
+        assert_eq!(
+            ResponseSummary::parse(&transaction_response()),
+            ResponseSummary {
+                gas_used: Some(10723),
+                exit_code: Some(0),
+                elapsed_time: Some(0.004),
+            }
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn it_records_transaction_outcome_in_span() {
+        use std::collections::HashMap;
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+
+        type Fields = Arc<Mutex<HashMap<String, String>>>;
+
+        struct Recorder(Fields);
+
+        impl Visit for Recorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                let value = format!("{value:?}");
+                self.0
+                    .lock()
+                    .unwrap()
+                    .insert(field.name().to_string(), value);
+            }
+        }
+
+        struct Subscriber(Fields);
+
+        impl tracing::Subscriber for Subscriber {
+            fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                span.record(&mut Recorder(self.0.clone()));
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut Recorder(self.0.clone()));
+            }
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &tracing::Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let fields = Fields::default();
+        let info = CallInfo {
+            operation: "emulate_transaction",
+            method_id: None,
+            address: &|| Some("0:abcd".to_string()),
+        };
+        tracing::subscriber::with_default(Subscriber(fields.clone()), || {
+            observe(info, || Ok(transaction_response())).unwrap();
+        });
+        let fields = fields.lock().unwrap();
+        assert_eq!(fields["operation"], "\"emulate_transaction\"");
+        assert_eq!(fields["address"], "\"0:abcd\"");
+        assert_eq!(fields["gas_used"], "10723");
+        assert_eq!(fields["exit_code"], "0");
+        assert_eq!(fields["elapsed_time"], "0.004");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn it_skips_address_without_subscriber() {
+        use std::cell::Cell;
+
+        let resolved = Cell::new(false);
+        let address = || {
+            resolved.set(true);
+            None
+        };
+        let info = CallInfo {
+            operation: "run_get_method",
+            method_id: Some(85143),
+            address: &address,
+        };
+        let subscriber = tracing::subscriber::NoSubscriber::default();
+        tracing::subscriber::with_default(subscriber, || {
+            observe(info, || Ok(transaction_response())).unwrap();
+        });
+        assert!(!resolved.get());
+    }
+}
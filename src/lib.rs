@@ -1,7 +1,49 @@
+mod cell;
+#[cfg(not(target_os = "emscripten"))]
+mod client;
+mod config;
+mod debugger;
+mod dictionary;
+mod error;
+#[cfg(test)]
+mod fixtures;
+mod gas_profiler;
+mod instrumentation;
+mod library_collection;
+mod library_resolver;
+#[cfg(not(target_os = "emscripten"))]
+mod log_capture;
+#[cfg(test)]
+mod mock_liteserver;
+mod prev_blocks_info;
+mod snapshot;
+mod stack;
+#[cfg(not(target_os = "emscripten"))]
 mod tonlibjson;
+mod transaction;
+mod tvm;
 mod tvm_emulator;
 mod tx_emulator;
+mod verbosity;
 
+pub use cell::*;
+#[cfg(not(target_os = "emscripten"))]
+pub use client::*;
+pub use config::*;
+pub use debugger::*;
+pub use error::*;
+pub use gas_profiler::*;
+pub use library_collection::*;
+pub use library_resolver::*;
+#[cfg(not(target_os = "emscripten"))]
+pub use log_capture::*;
+pub use prev_blocks_info::*;
+pub use snapshot::*;
+pub use stack::*;
+#[cfg(not(target_os = "emscripten"))]
 pub use tonlibjson::*;
+pub use transaction::*;
+pub use tvm::*;
 pub use tvm_emulator::*;
 pub use tx_emulator::*;
+pub use verbosity::*;
@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::cell::{Cell, CellError, CellHash};
+use crate::dictionary::{bits_to_bytes, build_hashmap, bytes_to_bits, parse_hashmap};
+
+/// Library code cells keyed by representation hash.
+///
+/// Serializes into `HashmapE 256 ^Cell` dictionary expected by
+/// `tvm_emulator_set_libraries` and `transaction_emulator_set_libs`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibraryCollection {
+    libraries: BTreeMap<CellHash, Arc<Cell>>,
+}
+
+impl LibraryCollection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses dictionary BoC, checking that every key is the hash of its library.
+    pub fn from_boc_b64(libs_boc: &str) -> Result<Self, CellError> {
+        let root = Cell::from_boc_b64(libs_boc)?;
+        let entries = parse_hashmap(&root, 256, &|parser| {
+            if parser.remaining_bits() != 0 || parser.remaining_references() != 1 {
+                return Err(CellError::new("library dictionary value must be ^Cell"));
+            }
+            parser.next_reference()
+        })?;
+
+        let mut collection = Self::new();
+        for (key, library) in entries {
+            if bits_to_bytes(&key) != library.hash() {
+                return Err(CellError::new(format!(
+                    "library {} is stored under a different key",
+                    library.hash_hex()
+                )));
+            }
+            collection.insert(library);
+        }
+        Ok(collection)
+    }
+
+    /// Adds library code cell. Returns its hash.
+    pub fn insert(&mut self, library: Arc<Cell>) -> CellHash {
+        let hash = library.hash();
+        self.libraries.insert(hash, library);
+        hash
+    }
+
+    /// Adds library from base64 encoded BoC of its code cell.
+    pub fn insert_boc_b64(&mut self, library_boc: &str) -> Result<CellHash, CellError> {
+        Ok(self.insert(Cell::from_boc_b64(library_boc)?))
+    }
+
+    pub fn merge(&mut self, other: LibraryCollection) {
+        self.libraries.extend(other.libraries);
+    }
+
+    pub fn get(&self, hash: &CellHash) -> Option<&Arc<Cell>> {
+        self.libraries.get(hash)
+    }
+
+    pub fn contains(&self, hash: &CellHash) -> bool {
+        self.libraries.contains_key(hash)
+    }
+
+    pub fn remove(&mut self, hash: &CellHash) -> Option<Arc<Cell>> {
+        self.libraries.remove(hash)
+    }
+
+    pub fn len(&self) -> usize {
+        self.libraries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.libraries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&CellHash, &Arc<Cell>)> {
+        self.libraries.iter()
+    }
+
+    /// Root cell of the dictionary, `None` if the collection is empty.
+    pub fn to_cell(&self) -> Result<Option<Cell>, CellError> {
+        let entries: Vec<_> = self
+            .libraries
+            .iter()
+            .map(|(hash, library)| (bytes_to_bits(hash), library.clone()))
+            .collect();
+        build_hashmap(&entries, 256, &|builder, library| {
+            builder.store_reference(library.clone()).map(|_| ())
+        })
+    }
+
+    /// Base64 encoded dictionary BoC, `None` if the collection is empty.
+    pub fn to_boc_b64(&self) -> Result<Option<String>, CellError> {
+        Ok(self.to_cell()?.map(|root| root.to_boc_b64()))
+    }
+}
+
+impl FromIterator<Arc<Cell>> for LibraryCollection {
+    fn from_iter<T: IntoIterator<Item = Arc<Cell>>>(iter: T) -> Self {
+        let mut collection = Self::new();
+        iter.into_iter().for_each(|library| {
+            collection.insert(library);
+        });
+        collection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::library;
+
+    #[test]
+    fn it_merges_collections() {
+        let mut first: LibraryCollection = (0..3).map(library).collect();
+        let second: LibraryCollection = (2..5).map(library).collect();
+        first.merge(second);
+        assert_eq!(first.len(), 5);
+        assert!(first.contains(&library(4).hash()));
+        assert_eq!(first.remove(&library(0).hash()), Some(library(0)));
+        assert_eq!(first.len(), 4);
+    }
+
+    #[test]
+    fn it_round_trips_dictionary() {
+        assert_eq!(LibraryCollection::new().to_boc_b64().unwrap(), None);
+
+        let mut collection: LibraryCollection = (0..10).map(library).collect();
+        let hash = collection
+            .insert_boc_b64(&library(100).to_boc_b64())
+            .unwrap();
+        assert_eq!(hash, library(100).hash());
+
+        let libs_boc = collection.to_boc_b64().unwrap().unwrap();
+        let parsed = LibraryCollection::from_boc_b64(&libs_boc).unwrap();
+        assert_eq!(parsed, collection);
+    }
+
+    #[test]
+    fn it_rejects_mismatched_keys() {
+        let entries = vec![(vec![false; 256], library(1))];
+        let root = build_hashmap(&entries, 256, &|builder, library| {
+            builder.store_reference(library.clone()).map(|_| ())
+        })
+        .unwrap()
+        .unwrap();
+        assert!(LibraryCollection::from_boc_b64(&root.to_boc_b64()).is_err());
+    }
+}
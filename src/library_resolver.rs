@@ -0,0 +1,290 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::cell::Cell;
+use crate::error::TonlibSysError;
+use crate::library_collection::LibraryCollection;
+use crate::tvm::TvmEmulator;
+
+pub const DEFAULT_MAX_LIBRARY_RETRIES: usize = 8;
+
+/// Source of library cells referenced by contract code, e.g. a liteserver or a local cache.
+pub trait LibraryResolver {
+    /// Returns base64 encoded BoC of library code cell with representation hash `hash` (hex).
+    fn resolve(&mut self, hash: &str) -> Option<String>;
+}
+
+impl<F: FnMut(&str) -> Option<String>> LibraryResolver for F {
+    fn resolve(&mut self, hash: &str) -> Option<String> {
+        self(hash)
+    }
+}
+
+/// Runs emulator calls, loading libraries reported in `missing_library` and retrying the call.
+///
+/// Loaded libraries are kept between calls and installed with `tvm_emulator_set_libraries`
+/// before every call, replacing libraries set on the emulator directly.
+pub struct LibraryLoader<R> {
+    resolver: R,
+    libraries: LibraryCollection,
+    max_retries: usize,
+}
+
+impl<R: LibraryResolver> LibraryLoader<R> {
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver,
+            libraries: LibraryCollection::new(),
+            max_retries: DEFAULT_MAX_LIBRARY_RETRIES,
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn add_library(&mut self, library: Arc<Cell>) {
+        self.libraries.insert(library);
+    }
+
+    pub fn add_libraries(&mut self, libraries: LibraryCollection) {
+        self.libraries.merge(libraries);
+    }
+
+    pub fn libraries(&self) -> &LibraryCollection {
+        &self.libraries
+    }
+
+    pub fn run_get_method(
+        &mut self,
+        emulator: &mut TvmEmulator,
+        method_id: i32,
+        stack_boc: &str,
+    ) -> Result<String, TonlibSysError> {
+        self.run(emulator, |emulator| {
+            emulator.run_get_method(method_id, stack_boc)
+        })
+    }
+
+    pub fn send_external_message(
+        &mut self,
+        emulator: &mut TvmEmulator,
+        message_body_boc: &str,
+    ) -> Result<String, TonlibSysError> {
+        self.run(emulator, |emulator| {
+            emulator.send_external_message(message_body_boc)
+        })
+    }
+
+    pub fn send_internal_message(
+        &mut self,
+        emulator: &mut TvmEmulator,
+        message_body_boc: &str,
+        amount: u64,
+    ) -> Result<String, TonlibSysError> {
+        self.run(emulator, |emulator| {
+            emulator.send_internal_message(message_body_boc, amount)
+        })
+    }
+
+    /// Returns the last response, which still has `missing_library` set
+    /// if the library can't be resolved or retries are exhausted.
+    fn run(
+        &mut self,
+        emulator: &mut TvmEmulator,
+        call: impl Fn(&mut TvmEmulator) -> Result<String, TonlibSysError>,
+    ) -> Result<String, TonlibSysError> {
+        self.install(emulator)?;
+        let mut retries = 0;
+        loop {
+            let response = call(emulator)?;
+            let Some(hash) = missing_library(&response) else {
+                return Ok(response);
+            };
+            if retries == self.max_retries || !self.load(&hash) {
+                return Ok(response);
+            }
+            self.install(emulator)?;
+            retries += 1;
+        }
+    }
+
+    fn load(&mut self, hash: &str) -> bool {
+        let Some(library) = self
+            .resolver
+            .resolve(hash)
+            .and_then(|boc| Cell::from_boc_b64(&boc).ok())
+        else {
+            return false;
+        };
+        if !library.hash_hex().eq_ignore_ascii_case(hash) {
+            return false;
+        }
+        self.add_library(library);
+        true
+    }
+
+    fn install(&self, emulator: &mut TvmEmulator) -> Result<(), TonlibSysError> {
+        match self.libraries.to_boc_b64()? {
+            Some(libs_boc) => emulator.set_libraries(&libs_boc),
+            None => Ok(()),
+        }
+    }
+}
+
+fn missing_library(response: &str) -> Option<String> {
+    let response: Value = serde_json::from_str(response).ok()?;
+    response["missing_library"].as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::cell::CellBuilder;
+    use crate::fixtures::{empty_stack, library, library_reference};
+
+    /// Libraries loaded one after another: code loads library `outer` with CTOS,
+    /// `outer` holds a reference to library `inner`, loaded with LDREFRTOS.
+    struct LibraryChain {
+        code: Arc<Cell>,
+        outer: Arc<Cell>,
+        inner: Arc<Cell>,
+    }
+
+    impl LibraryChain {
+        fn new() -> Self {
+            let inner = library(7);
+            let outer = Arc::new(
+                CellBuilder::new()
+                    .store_uint(32, 0xabcd)
+                    .unwrap()
+                    .store_reference(library_reference(&inner))
+                    .unwrap()
+                    .build()
+                    .unwrap(),
+            );
+            // PUSHREF, CTOS, LDREFRTOS
+            let code = CellBuilder::new()
+                .store_bytes(&[0x88, 0xd0, 0xd5])
+                .unwrap()
+                .store_reference(library_reference(&outer))
+                .unwrap()
+                .build()
+                .unwrap();
+            Self {
+                code: Arc::new(code),
+                outer,
+                inner,
+            }
+        }
+
+        fn emulator(&self) -> TvmEmulator {
+            let data = CellBuilder::new().build().unwrap();
+            TvmEmulator::new(&self.code.to_boc_b64(), &data.to_boc_b64(), 1).unwrap()
+        }
+
+        fn libraries(&self) -> HashMap<String, String> {
+            [&self.outer, &self.inner]
+                .into_iter()
+                .map(|library| (library.hash_hex().to_lowercase(), library.to_boc_b64()))
+                .collect()
+        }
+    }
+
+    fn response_json(response: &str) -> Value {
+        serde_json::from_str(response).unwrap()
+    }
+
+    #[test]
+    fn it_detects_missing_library() {
+        let response = r#"{"success": true, "vm_exit_code": 9, "missing_library": "ABCD"}"#;
+        assert_eq!(missing_library(response).as_deref(), Some("ABCD"));
+        let response = r#"{"success": true, "vm_exit_code": 0, "missing_library": null}"#;
+        assert_eq!(missing_library(response), None);
+    }
+
+    #[test]
+    fn it_loads_only_matching_libraries() {
+        let lib = library(1);
+        let lib_boc = lib.to_boc_b64();
+        let mut loader = LibraryLoader::new(|_: &str| Some(lib_boc.clone()));
+        assert!(loader.load(&lib.hash_hex().to_lowercase()));
+        assert!(!loader.load(&library(2).hash_hex()));
+        assert_eq!(loader.libraries().len(), 1);
+    }
+
+    #[test]
+    fn it_loads_missing_libraries_and_reruns() {
+        let chain = LibraryChain::new();
+        let libraries = chain.libraries();
+        let mut requested = vec![];
+        let mut loader = LibraryLoader::new(|hash: &str| {
+            let hash = hash.to_lowercase();
+            requested.push(hash.clone());
+            libraries.get(&hash).cloned()
+        });
+        let response = loader
+            .run_get_method(&mut chain.emulator(), 0, &empty_stack())
+            .unwrap();
+        let response = response_json(&response);
+        assert_eq!(response["vm_exit_code"], 0);
+        assert!(response["missing_library"].is_null());
+        assert_eq!(loader.libraries().len(), 2);
+        drop(loader);
+        assert_eq!(
+            requested,
+            [
+                chain.outer.hash_hex().to_lowercase(),
+                chain.inner.hash_hex().to_lowercase()
+            ]
+        );
+    }
+
+    #[test]
+    fn it_stops_after_max_retries() {
+        let chain = LibraryChain::new();
+        let libraries = chain.libraries();
+        let mut calls = 0;
+        let mut loader = LibraryLoader::new(|hash: &str| {
+            calls += 1;
+            libraries.get(&hash.to_lowercase()).cloned()
+        })
+        .with_max_retries(1);
+        let response = loader
+            .run_get_method(&mut chain.emulator(), 0, &empty_stack())
+            .unwrap();
+        let missing = response_json(&response)["missing_library"].clone();
+        drop(loader);
+        assert_eq!(calls, 1);
+        assert!(missing
+            .as_str()
+            .unwrap()
+            .eq_ignore_ascii_case(&chain.inner.hash_hex()));
+    }
+
+    #[test]
+    fn it_stops_on_wrong_library_cell() {
+        let chain = LibraryChain::new();
+        let wrong = library(99).to_boc_b64();
+        let mut calls = 0;
+        let mut loader = LibraryLoader::new(|_: &str| {
+            calls += 1;
+            Some(wrong.clone())
+        });
+        let response = loader
+            .run_get_method(&mut chain.emulator(), 0, &empty_stack())
+            .unwrap();
+        let missing = response_json(&response)["missing_library"].clone();
+        assert!(loader.libraries().is_empty());
+        drop(loader);
+        assert_eq!(calls, 1);
+        assert!(missing
+            .as_str()
+            .unwrap()
+            .eq_ignore_ascii_case(&chain.outer.hash_hex()));
+    }
+}
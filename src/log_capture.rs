@@ -0,0 +1,230 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::sync::{Arc, RwLock};
+
+use crate::tonlibjson::tonlib_client_set_log_callback;
+
+type Sink = Arc<dyn Fn(NativeLogRecord) + Send + Sync>;
+
+/// Active capture: its id and the callback.
+static SINK: RwLock<Option<(u64, Sink)>> = RwLock::new(None);
+
+/// Native log line (shared by tonlib and emulator), e.g.
+/// `[ 3][t 1][1700000000.1][tonlib-client.cpp:123][!Instance]` followed by a tab and the message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NativeLogRecord {
+    /// 0 - fatal, 1 - error, 2 - warning, 3 - info, 4 - debug, 5+ - verbose.
+    pub verbosity: u32,
+    /// Source location as `file.cpp:line`.
+    pub location: Option<String>,
+    pub message: String,
+}
+
+impl NativeLogRecord {
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        let rest = line.strip_prefix('[')?;
+        let (verbosity, mut rest) = rest.split_once(']')?;
+        let verbosity = verbosity.trim().parse().ok()?;
+
+        let mut location = None;
+        while let Some(tag) = rest.strip_prefix('[') {
+            let (value, tail) = tag.split_once(']')?;
+            if value.contains(".c") && value.contains(':') {
+                location = Some(value.to_string());
+            }
+            rest = tail;
+        }
+        Some(Self {
+            verbosity,
+            location,
+            message: rest.trim_start().to_string(),
+        })
+    }
+
+    /// Record passed to the log callback. Severity is the one given by native code,
+    /// the message may or may not carry the usual `[verbosity][thread]...` prefix.
+    fn from_callback(verbosity_level: c_int, message: &str) -> Self {
+        let verbosity = verbosity_level.max(0) as u32;
+        match Self::parse(message) {
+            Some(record) => Self {
+                verbosity,
+                ..record
+            },
+            None => Self {
+                verbosity,
+                location: None,
+                message: message.trim_end().to_string(),
+            },
+        }
+    }
+
+    #[cfg(feature = "log")]
+    pub fn level(&self) -> log::Level {
+        match self.verbosity {
+            0 | 1 => log::Level::Error,
+            2 => log::Level::Warn,
+            3 => log::Level::Info,
+            4 => log::Level::Debug,
+            _ => log::Level::Trace,
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    pub fn tracing_level(&self) -> tracing::Level {
+        match self.verbosity {
+            0 | 1 => tracing::Level::ERROR,
+            2 => tracing::Level::WARN,
+            3 => tracing::Level::INFO,
+            4 => tracing::Level::DEBUG,
+            _ => tracing::Level::TRACE,
+        }
+    }
+}
+
+/// Receives native log records through `tonlib_client_set_log_callback` until dropped.
+///
+/// The callback is process-wide, so starting a capture replaces the previous one.
+/// Records are delivered synchronously on the thread which logs them. Native code keeps
+/// writing to its log stream (stderr by default), use `setLogStream` to change that.
+///
+/// Output of `emulator_set_verbosity_level` is captured as well when emulator
+/// is linked statically together with tonlib (i.e. without `shared-tonlib`).
+pub struct LogCapture {
+    id: u64,
+}
+
+impl LogCapture {
+    /// Captures records with verbosity up to `max_verbosity`.
+    /// Native verbosity (see `set_verbosity`) limits which records are produced at all.
+    pub fn start(
+        max_verbosity: u32,
+        callback: impl Fn(NativeLogRecord) + Send + Sync + 'static,
+    ) -> Self {
+        let id = unique_id();
+        *SINK.write().unwrap_or_else(|error| error.into_inner()) = Some((id, Arc::new(callback)));
+        let max_verbosity = max_verbosity.min(c_int::MAX as u32) as c_int;
+        unsafe { tonlib_client_set_log_callback(max_verbosity, Some(forward_record)) };
+        Self { id }
+    }
+
+    /// Forwards native records to `log` with target `tonlib`.
+    #[cfg(feature = "log")]
+    pub fn forward_to_log(max_verbosity: u32) -> Self {
+        Self::start(max_verbosity, |record| {
+            log::log!(target: "tonlib", record.level(), "{}", record.message);
+        })
+    }
+
+    /// Emits native records as `tracing` events with target `tonlib`.
+    #[cfg(feature = "tracing")]
+    pub fn forward_to_tracing(max_verbosity: u32) -> Self {
+        Self::start(max_verbosity, |record| {
+            let location = record.location.as_deref();
+            let message = &record.message;
+            match record.tracing_level() {
+                tracing::Level::ERROR => {
+                    tracing::error!(target: "tonlib", location, "{message}")
+                }
+                tracing::Level::WARN => tracing::warn!(target: "tonlib", location, "{message}"),
+                tracing::Level::INFO => tracing::info!(target: "tonlib", location, "{message}"),
+                tracing::Level::DEBUG => {
+                    tracing::debug!(target: "tonlib", location, "{message}")
+                }
+                tracing::Level::TRACE => {
+                    tracing::trace!(target: "tonlib", location, "{message}")
+                }
+            }
+        })
+    }
+}
+
+impl Drop for LogCapture {
+    fn drop(&mut self) {
+        let mut sink = SINK.write().unwrap_or_else(|error| error.into_inner());
+        if sink.as_ref().is_some_and(|(id, _)| *id == self.id) {
+            unsafe { tonlib_client_set_log_callback(0, None) };
+            *sink = None;
+        }
+    }
+}
+
+extern "C" fn forward_record(verbosity_level: c_int, message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+    // clone the sink, so the callback may start or stop captures itself
+    let sink = match SINK.read() {
+        Ok(sink) => sink.as_ref().map(|(_, sink)| sink.clone()),
+        Err(_) => return,
+    };
+    let Some(sink) = sink else {
+        return;
+    };
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    let record = NativeLogRecord::from_callback(verbosity_level, &message);
+    // unwinding into native code is undefined behavior
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sink(record)));
+}
+
+fn unique_id() -> u64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_native_log_lines() {
+        let record = NativeLogRecord::parse(
+            "[ 2][t 1][1700000000.123456][tonlib-client.cpp:123][!Instance]\tconnection lost\n",
+        )
+        .unwrap();
+        assert_eq!(
+            record,
+            NativeLogRecord {
+                verbosity: 2,
+                location: Some("tonlib-client.cpp:123".to_string()),
+                message: "connection lost".to_string(),
+            }
+        );
+        assert!(NativeLogRecord::parse("not a log line").is_none());
+    }
+
+    #[test]
+    fn it_forwards_callback_records() {
+        let records = Arc::new(Mutex::new(vec![]));
+        let capture = {
+            let records = records.clone();
+            LogCapture::start(4, move |record| records.lock().unwrap().push(record))
+        };
+        forward_record(
+            1,
+            c"[ 1][t 2][1700000000.1][emulator.cpp:10]\tbad config\n".as_ptr(),
+        );
+        forward_record(4, c"plain message".as_ptr());
+        drop(capture);
+        forward_record(1, c"after drop".as_ptr());
+
+        assert_eq!(
+            *records.lock().unwrap(),
+            [
+                NativeLogRecord {
+                    verbosity: 1,
+                    location: Some("emulator.cpp:10".to_string()),
+                    message: "bad config".to_string(),
+                },
+                NativeLogRecord {
+                    verbosity: 4,
+                    location: None,
+                    message: "plain message".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::cell::CellError;
+use crate::stack::StackValue;
+
+const MAX_LAST_MC_BLOCKS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockIdExt {
+    pub workchain: i32,
+    pub shard: u64,
+    pub seqno: u32,
+    pub root_hash: [u8; 32],
+    pub file_hash: [u8; 32],
+}
+
+impl BlockIdExt {
+    /// `[ wc:Integer shard:Integer seqno:Integer root_hash:Integer file_hash:Integer ]`
+    pub fn to_stack_value(&self) -> StackValue {
+        StackValue::Tuple(vec![
+            StackValue::Int(self.workchain as i128),
+            StackValue::Int(self.shard as i128),
+            StackValue::Int(self.seqno as i128),
+            StackValue::Uint256(self.root_hash),
+            StackValue::Uint256(self.file_hash),
+        ])
+    }
+}
+
+/// Tuple of previous blocks, the 13th element of c7:
+/// `[ last_mc_blocks:[BlockIdExt...] prev_key_block:BlockIdExt last_mc_blocks_100:[BlockIdExt...] ]`.
+///
+/// `last_mc_blocks_100` (masterchain blocks with seqno divisible by 100) is present since global version 10.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrevBlocksInfo {
+    pub last_mc_blocks: Vec<BlockIdExt>,
+    pub prev_key_block: BlockIdExt,
+    pub last_mc_blocks_100: Option<Vec<BlockIdExt>>,
+}
+
+impl PrevBlocksInfo {
+    pub fn new(prev_key_block: BlockIdExt) -> Self {
+        Self {
+            last_mc_blocks: vec![],
+            prev_key_block,
+            last_mc_blocks_100: None,
+        }
+    }
+
+    /// Blocks are expected newest first, up to 16 of them.
+    pub fn with_last_mc_blocks(mut self, last_mc_blocks: Vec<BlockIdExt>) -> Self {
+        self.last_mc_blocks = last_mc_blocks;
+        self
+    }
+
+    /// Blocks are expected newest first, up to 16 of them.
+    pub fn with_last_mc_blocks_100(mut self, last_mc_blocks_100: Vec<BlockIdExt>) -> Self {
+        self.last_mc_blocks_100 = Some(last_mc_blocks_100);
+        self
+    }
+
+    pub fn to_stack_value(&self) -> Result<StackValue, CellError> {
+        let block_list = |blocks: &[BlockIdExt]| {
+            if blocks.len() > MAX_LAST_MC_BLOCKS {
+                return Err(CellError::new(format!(
+                    "expected at most {MAX_LAST_MC_BLOCKS} blocks, got {}",
+                    blocks.len()
+                )));
+            }
+            Ok(StackValue::Tuple(
+                blocks.iter().map(BlockIdExt::to_stack_value).collect(),
+            ))
+        };
+
+        let mut items = vec![
+            block_list(&self.last_mc_blocks)?,
+            self.prev_key_block.to_stack_value(),
+        ];
+        if let Some(last_mc_blocks_100) = &self.last_mc_blocks_100 {
+            items.push(block_list(last_mc_blocks_100)?);
+        }
+        Ok(StackValue::Tuple(items))
+    }
+
+    /// Base64 encoded BoC for `tvm_emulator_set_prev_blocks_info` and `transaction_emulator_set_prev_blocks_info`.
+    pub fn to_boc_b64(&self) -> Result<String, CellError> {
+        self.to_stack_value()?.to_boc_b64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(seqno: u32) -> BlockIdExt {
+        BlockIdExt {
+            workchain: -1,
+            shard: 0x8000_0000_0000_0000,
+            seqno,
+            root_hash: [1; 32],
+            file_hash: [2; 32],
+        }
+    }
+
+    #[test]
+    fn it_builds_prev_blocks_tuple() {
+        let info = PrevBlocksInfo::new(block(100)).with_last_mc_blocks(vec![block(3), block(2)]);
+        let StackValue::Tuple(items) = info.to_stack_value().unwrap() else {
+            panic!("expected tuple");
+        };
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1], block(100).to_stack_value());
+        let StackValue::Tuple(key_block) = &items[1] else {
+            panic!("expected tuple");
+        };
+        // shard is stored as unsigned
+        assert_eq!(key_block[1], StackValue::Int(1 << 63));
+
+        let info = info.with_last_mc_blocks_100(vec![block(100)]);
+        let StackValue::Tuple(items) = info.to_stack_value().unwrap() else {
+            panic!("expected tuple");
+        };
+        assert_eq!(items.len(), 3);
+        assert!(info.to_boc_b64().is_ok());
+    }
+
+    #[test]
+    fn it_limits_block_lists() {
+        let info = PrevBlocksInfo::new(block(1)).with_last_mc_blocks((0..17).map(block).collect());
+        assert!(info.to_boc_b64().is_err());
+    }
+}
@@ -0,0 +1,384 @@
+use std::path::PathBuf;
+
+use serde_json::{Map, Value};
+
+use crate::cell::Cell;
+use crate::error::TonlibSysError;
+use crate::transaction::TransactionEmulator;
+use crate::tvm::TvmEmulator;
+
+/// Set to `1` to write current outputs into snapshot files instead of comparing.
+pub const BLESS_ENV: &str = "TONLIB_SYS_BLESS";
+
+/// Fields kept in snapshots, everything else (`elapsed_time`, ...) is dropped.
+const SNAPSHOT_FIELDS: &[&str] = &[
+    "success",
+    "error",
+    "vm_exit_code",
+    "gas_used",
+    "accepted",
+    "stack",
+    "missing_library",
+    "transaction",
+    "shard_account",
+    "actions",
+];
+
+/// Emulator call with all inputs fixed, so its output is reproducible.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    /// Snapshot file name, without extension.
+    pub name: String,
+    pub kind: ScenarioKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum ScenarioKind {
+    GetMethod {
+        code_boc: String,
+        data_boc: String,
+        method_id: i32,
+        stack_boc: String,
+        /// Passed to `set_c7` together with the address and unixtime, if any.
+        config_boc: Option<String>,
+        address: String,
+        unixtime: u32,
+        balance: u64,
+    },
+    Transaction {
+        config_boc: String,
+        shard_account_boc: String,
+        message_boc: String,
+        unixtime: u32,
+        lt: u64,
+        rand_seed_hex: String,
+    },
+}
+
+impl ScenarioKind {
+    /// Runs the scenario and returns response JSON.
+    ///
+    /// Responses of TVM errors and not accepted external messages are returned as is,
+    /// since they are part of contract behavior. Other emulator failures are errors.
+    pub fn run(&self, vm_log_verbosity: u32) -> Result<Value, TonlibSysError> {
+        let result = match self {
+            ScenarioKind::GetMethod {
+                code_boc,
+                data_boc,
+                method_id,
+                stack_boc,
+                config_boc,
+                address,
+                unixtime,
+                balance,
+            } => {
+                let mut emulator = TvmEmulator::new(code_boc, data_boc, vm_log_verbosity)?;
+                if let Some(config_boc) = config_boc {
+                    let rand_seed = "0".repeat(64);
+                    emulator.set_c7(address, *unixtime, *balance, &rand_seed, config_boc)?;
+                }
+                emulator.run_get_method(*method_id, stack_boc)
+            }
+            ScenarioKind::Transaction {
+                config_boc,
+                shard_account_boc,
+                message_boc,
+                unixtime,
+                lt,
+                rand_seed_hex,
+            } => {
+                let mut emulator = TransactionEmulator::new(config_boc, vm_log_verbosity)?;
+                emulator.set_unixtime(*unixtime)?;
+                emulator.set_lt(*lt)?;
+                emulator.set_rand_seed(rand_seed_hex)?;
+                emulator.emulate_transaction(shard_account_boc, message_boc)
+            }
+        };
+        match result {
+            Ok(response) => Ok(serde_json::from_str(&response)?),
+            Err(
+                TonlibSysError::VmError { response, .. }
+                | TonlibSysError::ExternalNotAccepted { response, .. },
+            ) => Ok(serde_json::from_str(&response)?),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Keeps reproducible fields of emulator response.
+///
+/// `gas_used` becomes a number and BoCs are re-serialized, so snapshots don't depend on
+/// BoC flags chosen by a particular emulator version. With `include_vm_log`, `vm_log` is kept
+/// with native log timestamps removed.
+pub fn normalize_response(response: &Value, include_vm_log: bool) -> Value {
+    let mut snapshot = Map::new();
+    for field in SNAPSHOT_FIELDS {
+        let Some(value) = response.get(*field) else {
+            continue;
+        };
+        let value = match *field {
+            "gas_used" => match value {
+                Value::String(gas) => gas.parse::<u64>().map_or(value.clone(), Value::from),
+                _ => value.clone(),
+            },
+            "stack" | "transaction" | "shard_account" | "actions" => match value {
+                Value::String(boc) => Value::String(canonical_boc(boc)),
+                _ => value.clone(),
+            },
+            _ => value.clone(),
+        };
+        snapshot.insert(field.to_string(), value);
+    }
+    if include_vm_log {
+        if let Some(vm_log) = response["vm_log"].as_str() {
+            snapshot.insert("vm_log".to_string(), strip_timestamps(vm_log).into());
+        }
+    }
+    Value::Object(snapshot)
+}
+
+fn canonical_boc(boc: &str) -> String {
+    Cell::from_boc_b64(boc).map_or(boc.to_string(), |cell| cell.to_boc_b64())
+}
+
+/// Removes `[1700000000.123456]` tags of native log lines.
+fn strip_timestamps(log: &str) -> String {
+    let mut result = String::with_capacity(log.len());
+    let mut rest = log;
+    while let Some(start) = rest.find('[') {
+        let tag = &rest[start + 1..];
+        let end = tag.find(']');
+        let is_timestamp = end.is_some_and(|end| {
+            let tag = &tag[..end];
+            tag.contains('.') && tag.chars().all(|c| c.is_ascii_digit() || c == '.')
+        });
+        result.push_str(&rest[..start]);
+        match end {
+            Some(end) if is_timestamp => rest = &tag[end + 1..],
+            _ => {
+                result.push('[');
+                rest = tag;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotOutcome {
+    Matched,
+    /// Snapshot was written because blessing is enabled.
+    Blessed,
+    Missing,
+    Mismatch {
+        expected: Value,
+        actual: Value,
+    },
+}
+
+/// Compares scenario outputs with JSON snapshots stored in a directory.
+pub struct SnapshotRunner {
+    dir: PathBuf,
+    bless: bool,
+    include_vm_log: bool,
+    vm_log_verbosity: u32,
+}
+
+impl SnapshotRunner {
+    /// Blessing is enabled if [`BLESS_ENV`] is set to `1`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            bless: std::env::var(BLESS_ENV).is_ok_and(|bless| bless == "1"),
+            include_vm_log: false,
+            vm_log_verbosity: 0,
+        }
+    }
+
+    pub fn with_bless(mut self, bless: bool) -> Self {
+        self.bless = bless;
+        self
+    }
+
+    /// Snapshots `vm_log` too, which is sensitive to any change in the emulator.
+    pub fn with_vm_log(mut self, vm_log_verbosity: u32) -> Self {
+        self.include_vm_log = true;
+        self.vm_log_verbosity = vm_log_verbosity;
+        self
+    }
+
+    pub fn snapshot_path(&self, scenario: &Scenario) -> PathBuf {
+        self.dir.join(format!("{}.json", scenario.name))
+    }
+
+    pub fn check(&self, scenario: &Scenario) -> Result<SnapshotOutcome, TonlibSysError> {
+        let response = scenario.kind.run(self.vm_log_verbosity)?;
+        self.compare(scenario, normalize_response(&response, self.include_vm_log))
+    }
+
+    fn compare(
+        &self,
+        scenario: &Scenario,
+        actual: Value,
+    ) -> Result<SnapshotOutcome, TonlibSysError> {
+        let path = self.snapshot_path(scenario);
+        if self.bless {
+            std::fs::create_dir_all(&self.dir)?;
+            let mut snapshot = serde_json::to_string_pretty(&actual)?;
+            snapshot.push('\n');
+            std::fs::write(&path, snapshot)?;
+            return Ok(SnapshotOutcome::Blessed);
+        }
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(expected) => serde_json::from_str::<Value>(&expected)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(SnapshotOutcome::Missing)
+            }
+            Err(error) => return Err(error.into()),
+        };
+        if expected == actual {
+            Ok(SnapshotOutcome::Matched)
+        } else {
+            Ok(SnapshotOutcome::Mismatch { expected, actual })
+        }
+    }
+
+    /// Checks all scenarios and panics with a report if any snapshot is missing or differs.
+    pub fn assert_all(&self, scenarios: &[Scenario]) {
+        let mut failures = vec![];
+        for scenario in scenarios {
+            match self.check(scenario) {
+                Ok(SnapshotOutcome::Matched | SnapshotOutcome::Blessed) => {}
+                Ok(SnapshotOutcome::Missing) => failures.push(format!(
+                    "{}: no snapshot at {}, run with {BLESS_ENV}=1 to create it",
+                    scenario.name,
+                    self.snapshot_path(scenario).display()
+                )),
+                Ok(SnapshotOutcome::Mismatch { expected, actual }) => {
+                    for field in changed_fields(&expected, &actual) {
+                        failures.push(format!(
+                            "{}: {field}: expected {}, got {}",
+                            scenario.name,
+                            expected.get(&field).unwrap_or(&Value::Null),
+                            actual.get(&field).unwrap_or(&Value::Null)
+                        ));
+                    }
+                }
+                Err(error) => failures.push(format!("{}: {error}", scenario.name)),
+            }
+        }
+        assert!(
+            failures.is_empty(),
+            "snapshot mismatch, run with {BLESS_ENV}=1 to accept:\n{}",
+            failures.join("\n")
+        );
+    }
+}
+
+fn changed_fields(expected: &Value, actual: &Value) -> Vec<String> {
+    let empty = Map::new();
+    let expected = expected.as_object().unwrap_or(&empty);
+    let actual = actual.as_object().unwrap_or(&empty);
+    let mut fields: Vec<String> = expected
+        .keys()
+        .chain(actual.keys())
+        .filter(|field| expected.get(*field) != actual.get(*field))
+        .cloned()
+        .collect();
+    fields.sort();
+    fields.dedup();
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures::read_fixture;
+
+    #[test]
+    fn it_normalizes_responses() {
+        let response = json!({
+            "success": true,
+            "vm_exit_code": 0,
+            "gas_used": "1212",
+            "elapsed_time": 0.0123,
+            "stack": "te6cckEBAQEABQAABgAAANAJX0U=",
+            "vm_log": "[ 3][t 0][1700000000.123456][emulator.cpp:1] started\nexecute SETCP 0",
+        });
+        assert_eq!(
+            normalize_response(&response, true),
+            json!({
+                "success": true,
+                "vm_exit_code": 0,
+                "gas_used": 1212,
+                "stack": "te6cckEBAQEABQAABgAAANAJX0U=",
+                "vm_log": "[ 3][t 0][emulator.cpp:1] started\nexecute SETCP 0",
+            })
+        );
+        assert!(normalize_response(&response, false).get("vm_log").is_none());
+    }
+
+    #[test]
+    fn it_keeps_transaction_and_shard_account() {
+        let expected: Value =
+            serde_json::from_str(&read_fixture("expected/wallet_v3r2_transfer.json")).unwrap();
+        for field in ["transaction", "shard_account", "actions"] {
+            assert!(
+                expected[field].is_string(),
+                "{field} is not in the golden file"
+            );
+        }
+        let mut response = expected.clone();
+        response["vm_log"] = json!("execute SETCP 0");
+        response["elapsed_time"] = json!(0.0123);
+        assert_eq!(normalize_response(&response, false), expected);
+    }
+
+    #[test]
+    fn it_blesses_and_compares_snapshots() {
+        let dir = std::env::temp_dir().join(format!("tonlib-sys-snapshot-{}", std::process::id()));
+        let scenario = Scenario {
+            name: "scenario".to_string(),
+            kind: ScenarioKind::GetMethod {
+                code_boc: String::new(),
+                data_boc: String::new(),
+                method_id: 0,
+                stack_boc: String::new(),
+                config_boc: None,
+                address: String::new(),
+                unixtime: 0,
+                balance: 0,
+            },
+        };
+        let runner = SnapshotRunner::new(&dir).with_bless(false);
+        let actual = json!({"success": true, "vm_exit_code": 0});
+        assert_eq!(
+            runner.compare(&scenario, actual.clone()).unwrap(),
+            SnapshotOutcome::Missing
+        );
+
+        let blessing = SnapshotRunner::new(&dir).with_bless(true);
+        assert_eq!(
+            blessing.compare(&scenario, actual.clone()).unwrap(),
+            SnapshotOutcome::Blessed
+        );
+        assert_eq!(
+            runner.compare(&scenario, actual).unwrap(),
+            SnapshotOutcome::Matched
+        );
+
+        let changed = json!({"success": true, "vm_exit_code": 9});
+        assert!(matches!(
+            runner.compare(&scenario, changed.clone()).unwrap(),
+            SnapshotOutcome::Mismatch { .. }
+        ));
+        assert_eq!(
+            changed_fields(&json!({"success": true, "vm_exit_code": 0}), &changed),
+            vec!["vm_exit_code".to_string()]
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
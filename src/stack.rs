@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use crate::cell::{Cell, CellBuilder, CellError};
+
+const MAX_TUPLE_LEN: usize = 255;
+
+/// TVM stack value, serializable as `VmStackValue`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackValue {
+    Null,
+    Int(i128),
+    /// Non-negative integer given as 256-bit big-endian number, e.g. a hash.
+    Uint256([u8; 32]),
+    Cell(Arc<Cell>),
+    Tuple(Vec<StackValue>),
+}
+
+impl StackValue {
+    pub fn store(&self, builder: &mut CellBuilder) -> Result<(), CellError> {
+        match self {
+            StackValue::Null => {
+                builder.store_uint(8, 0x00)?;
+            }
+            StackValue::Int(value) => match i64::try_from(*value) {
+                Ok(value) => {
+                    // vm_stk_tinyint#01 value:int64
+                    builder.store_uint(8, 0x01)?.store_uint(64, value as u64)?;
+                }
+                Err(_) => {
+                    // vm_stk_int#0201_ value:int257
+                    let sign = *value < 0;
+                    builder.store_uint(15, 0x0100)?;
+                    builder.store_bits(&[sign; 257 - 128])?;
+                    builder
+                        .store_uint(64, (*value >> 64) as u64)?
+                        .store_uint(64, *value as u64)?;
+                }
+            },
+            StackValue::Uint256(value) => {
+                builder.store_uint(15, 0x0100)?.store_bit(false)?;
+                builder.store_bytes(value)?;
+            }
+            StackValue::Cell(cell) => {
+                // vm_stk_cell#03 cell:^Cell
+                builder.store_uint(8, 0x03)?.store_reference(cell.clone())?;
+            }
+            StackValue::Tuple(items) => store_tuple(builder, items)?,
+        }
+        Ok(())
+    }
+
+    pub fn to_cell(&self) -> Result<Cell, CellError> {
+        let mut builder = CellBuilder::new();
+        self.store(&mut builder)?;
+        builder.build()
+    }
+
+    pub fn to_boc_b64(&self) -> Result<String, CellError> {
+        Ok(self.to_cell()?.to_boc_b64())
+    }
+}
+
+/// vm_stk_tuple#07 len:(## 16) data:(VmTuple len), laid out the same way as `StackEntry::serialize` in TON.
+fn store_tuple(builder: &mut CellBuilder, items: &[StackValue]) -> Result<(), CellError> {
+    if items.len() > MAX_TUPLE_LEN {
+        return Err(CellError::new(format!(
+            "tuple can't have more than {MAX_TUPLE_LEN} items"
+        )));
+    }
+    let mut head: Option<Arc<Cell>> = None;
+    let mut tail: Option<Arc<Cell>> = None;
+    for (i, item) in items.iter().enumerate() {
+        std::mem::swap(&mut head, &mut tail);
+        if i > 1 {
+            let mut pair = CellBuilder::new();
+            pair.store_reference(tail.take().expect("tuple tail"))?
+                .store_reference(head.take().expect("tuple head"))?;
+            head = Some(Arc::new(pair.build()?));
+        }
+        tail = Some(Arc::new(item.to_cell()?));
+    }
+    builder
+        .store_uint(8, 0x07)?
+        .store_uint(16, items.len() as u64)?;
+    for reference in [head, tail].into_iter().flatten() {
+        builder.store_reference(reference)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_integers() {
+        let cell = StackValue::Int(-1).to_cell().unwrap();
+        let mut parser = cell.parser();
+        assert_eq!(parser.load_uint(8).unwrap(), 0x01);
+        assert_eq!(parser.load_uint(64).unwrap(), u64::MAX);
+
+        let cell = StackValue::Int(1 << 64).to_cell().unwrap();
+        assert_eq!(cell.bit_len(), 15 + 257);
+        let mut parser = cell.parser();
+        assert_eq!(parser.load_uint(16).unwrap(), 0x0200);
+        assert_eq!(parser.load_uint(64).unwrap(), 0);
+        assert_eq!(parser.load_uint(64).unwrap(), 0);
+        assert_eq!(parser.load_uint(64).unwrap(), 1);
+        assert_eq!(parser.load_uint(64).unwrap(), 0);
+
+        let cell = StackValue::Uint256([0xff; 32]).to_cell().unwrap();
+        let mut parser = cell.parser();
+        assert_eq!(parser.load_uint(16).unwrap(), 0x0200);
+        assert!(parser.load_bit().unwrap());
+    }
+
+    #[test]
+    fn it_serializes_tuples() {
+        let items: Vec<_> = (0..4).map(StackValue::Int).collect();
+        let item_hash = |i: usize| items[i].to_cell().unwrap().hash();
+
+        let single = StackValue::Tuple(items[..1].to_vec()).to_cell().unwrap();
+        assert_eq!(single.references().len(), 1);
+        assert_eq!(single.references()[0].hash(), item_hash(0));
+
+        let pair = StackValue::Tuple(items[..2].to_vec()).to_cell().unwrap();
+        assert_eq!(pair.references()[0].hash(), item_hash(0));
+        assert_eq!(pair.references()[1].hash(), item_hash(1));
+
+        let quad = StackValue::Tuple(items.clone()).to_cell().unwrap();
+        let mut parser = quad.parser();
+        assert_eq!(parser.load_uint(8).unwrap(), 0x07);
+        assert_eq!(parser.load_uint(16).unwrap(), 4);
+        // head = ((0, 1), 2), tail = 3
+        let head = &quad.references()[0];
+        assert_eq!(head.references()[1].hash(), item_hash(2));
+        assert_eq!(head.references()[0].references()[0].hash(), item_hash(0));
+        assert_eq!(head.references()[0].references()[1].hash(), item_hash(1));
+        assert_eq!(quad.references()[1].hash(), item_hash(3));
+
+        let empty = StackValue::Tuple(vec![]).to_cell().unwrap();
+        assert_eq!((empty.bit_len(), empty.references().len()), (24, 0));
+    }
+}
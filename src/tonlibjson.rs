@@ -19,6 +19,19 @@ extern "C" {
     pub fn tonlib_client_json_destroy(client: *mut std::os::raw::c_void);
 
     pub fn tonlib_client_set_verbosity_level(verbosity_level: u32);
+
+    /// Sets process-wide callback for native log messages with verbosity up to
+    /// `max_verbosity_level`, `None` removes it. The callback is invoked on the logging thread
+    /// and `message` is valid only during the call.
+    pub fn tonlib_client_set_log_callback(
+        max_verbosity_level: std::os::raw::c_int,
+        callback: Option<
+            extern "C" fn(
+                verbosity_level: std::os::raw::c_int,
+                message: *const std::os::raw::c_char,
+            ),
+        >,
+    );
 }
 
 #[cfg(test)]
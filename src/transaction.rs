@@ -0,0 +1,350 @@
+use std::os::raw::c_void;
+
+use crate::cell::{Cell, CellError, CellParser};
+use crate::error::{check_response, TonlibSysError};
+use crate::instrumentation::{observe, CallInfo};
+use crate::tvm::{c_string, check_setter, take_response};
+use crate::tx_emulator::*;
+
+/// Owned transaction emulator handle. Destroyed on drop.
+///
+/// Like [`TvmEmulator`](crate::TvmEmulator), it is `Send` but not `Sync`.
+pub struct TransactionEmulator {
+    ptr: *mut c_void,
+}
+
+unsafe impl Send for TransactionEmulator {}
+
+impl TransactionEmulator {
+    pub fn new(config_boc: &str, vm_log_verbosity: u32) -> Result<Self, TonlibSysError> {
+        let config = c_string(config_boc, "config_boc")?;
+        let ptr = unsafe { transaction_emulator_create(config.as_ptr(), vm_log_verbosity) };
+        if ptr.is_null() {
+            return Err(TonlibSysError::NullHandle {
+                function: "transaction_emulator_create",
+            });
+        }
+        Ok(Self { ptr })
+    }
+
+    pub fn set_unixtime(&mut self, unixtime: u32) -> Result<(), TonlibSysError> {
+        let result = unsafe { transaction_emulator_set_unixtime(self.ptr, unixtime) };
+        check_setter(result, "transaction_emulator_set_unixtime")
+    }
+
+    pub fn set_lt(&mut self, lt: u64) -> Result<(), TonlibSysError> {
+        let result = unsafe { transaction_emulator_set_lt(self.ptr, lt) };
+        check_setter(result, "transaction_emulator_set_lt")
+    }
+
+    pub fn set_rand_seed(&mut self, rand_seed_hex: &str) -> Result<(), TonlibSysError> {
+        let rand_seed = c_string(rand_seed_hex, "rand_seed_hex")?;
+        let result = unsafe { transaction_emulator_set_rand_seed(self.ptr, rand_seed.as_ptr()) };
+        check_setter(result, "transaction_emulator_set_rand_seed")
+    }
+
+    pub fn set_ignore_chksig(&mut self, ignore_chksig: bool) -> Result<(), TonlibSysError> {
+        let result = unsafe { transaction_emulator_set_ignore_chksig(self.ptr, ignore_chksig) };
+        check_setter(result, "transaction_emulator_set_ignore_chksig")
+    }
+
+    pub fn set_config(&mut self, config_boc: &str) -> Result<(), TonlibSysError> {
+        let config = c_string(config_boc, "config_boc")?;
+        let result = unsafe { transaction_emulator_set_config(self.ptr, config.as_ptr()) };
+        check_setter(result, "transaction_emulator_set_config")
+    }
+
+    pub fn set_libs(&mut self, libs_boc: &str) -> Result<(), TonlibSysError> {
+        let libs = c_string(libs_boc, "libs_boc")?;
+        let result = unsafe { transaction_emulator_set_libs(self.ptr, libs.as_ptr()) };
+        check_setter(result, "transaction_emulator_set_libs")
+    }
+
+    pub fn set_debug_enabled(&mut self, debug_enabled: bool) -> Result<(), TonlibSysError> {
+        let result = unsafe { transaction_emulator_set_debug_enabled(self.ptr, debug_enabled) };
+        check_setter(result, "transaction_emulator_set_debug_enabled")
+    }
+
+    pub fn set_prev_blocks_info(&mut self, info_boc: &str) -> Result<(), TonlibSysError> {
+        let info = c_string(info_boc, "info_boc")?;
+        let result = unsafe { transaction_emulator_set_prev_blocks_info(self.ptr, info.as_ptr()) };
+        check_setter(result, "transaction_emulator_set_prev_blocks_info")
+    }
+
+    /// Returns JSON response as described in [`transaction_emulator_emulate_transaction`].
+    pub fn emulate_transaction(
+        &mut self,
+        shard_account_boc: &str,
+        message_boc: &str,
+    ) -> Result<String, TonlibSysError> {
+        let shard_account = c_string(shard_account_boc, "shard_account_boc")?;
+        let message = c_string(message_boc, "message_boc")?;
+        let info = CallInfo {
+            operation: "emulate_transaction",
+            method_id: None,
+            address: &|| shard_account_address(shard_account_boc),
+        };
+        observe(info, || {
+            let response = unsafe {
+                take_response(
+                    transaction_emulator_emulate_transaction(
+                        self.ptr,
+                        shard_account.as_ptr(),
+                        message.as_ptr(),
+                    ),
+                    "transaction_emulator_emulate_transaction",
+                )?
+            };
+            check_response(&response)?;
+            Ok(response)
+        })
+    }
+
+    /// Returns JSON response as described in [`transaction_emulator_emulate_tick_tock_transaction`].
+    pub fn emulate_tick_tock_transaction(
+        &mut self,
+        shard_account_boc: &str,
+        is_tock: bool,
+    ) -> Result<String, TonlibSysError> {
+        let shard_account = c_string(shard_account_boc, "shard_account_boc")?;
+        let info = CallInfo {
+            operation: "emulate_tick_tock_transaction",
+            method_id: None,
+            address: &|| shard_account_address(shard_account_boc),
+        };
+        observe(info, || {
+            let response = unsafe {
+                take_response(
+                    transaction_emulator_emulate_tick_tock_transaction(
+                        self.ptr,
+                        shard_account.as_ptr(),
+                        is_tock,
+                    ),
+                    "transaction_emulator_emulate_tick_tock_transaction",
+                )?
+            };
+            check_response(&response)?;
+            Ok(response)
+        })
+    }
+}
+
+impl Drop for TransactionEmulator {
+    fn drop(&mut self) {
+        unsafe { transaction_emulator_destroy(self.ptr) }
+    }
+}
+
+/// Outcome of the compute phase, decoded from a transaction BoC.
+///
+/// Transaction emulator responses carry no `gas_used` or `vm_exit_code`, these values
+/// exist only inside the returned `transaction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComputePhase {
+    pub success: bool,
+    pub gas_used: u64,
+    pub exit_code: i32,
+}
+
+impl ComputePhase {
+    /// Returns `None` if the compute phase was skipped or the transaction kind has none
+    /// of interest (storage, split and merge transactions).
+    pub fn from_transaction_boc(transaction_boc: &str) -> Result<Option<Self>, CellError> {
+        let transaction = Cell::from_boc_b64(transaction_boc)?;
+        let mut parser = transaction.parser();
+        if parser.load_uint(4)? != 0b0111 {
+            return Err(CellError::new("not a transaction"));
+        }
+        // account_addr, lt, prev_trans_hash, prev_trans_lt, now, outmsg_cnt,
+        // orig_status, end_status
+        skip_bits(&mut parser, 256 + 64 + 256 + 64 + 32 + 15 + 2 + 2)?;
+        parser.next_reference()?; // in_msg and out_msgs
+        skip_currency_collection(&mut parser)?; // total_fees
+        parser.next_reference()?; // state_update
+        let description = parser.next_reference()?;
+
+        let mut parser = description.parser();
+        match parser.load_uint(4)? {
+            // trans_ord$0000 credit_first:Bool
+            0b0000 => {
+                parser.load_bit()?;
+                if parser.load_bit()? {
+                    skip_storage_phase(&mut parser)?;
+                }
+                if parser.load_bit()? {
+                    // tr_phase_credit$_ due_fees_collected:(Maybe Grams) credit:CurrencyCollection
+                    if parser.load_bit()? {
+                        skip_var_uint(&mut parser, 4)?;
+                    }
+                    skip_currency_collection(&mut parser)?;
+                }
+            }
+            // trans_tick_tock$001 is_tock:Bool storage_ph:TrStoragePhase
+            0b0010 | 0b0011 => skip_storage_phase(&mut parser)?,
+            _ => return Ok(None),
+        }
+        // tr_phase_compute_skipped$0 | tr_phase_compute_vm$1
+        if !parser.load_bit()? {
+            return Ok(None);
+        }
+        let success = parser.load_bit()?;
+        parser.load_bits(2)?; // msg_state_used, account_activated
+        skip_var_uint(&mut parser, 4)?; // gas_fees
+        let details = parser.next_reference()?;
+        let mut parser = details.parser();
+        let gas_used = load_var_uint(&mut parser, 3)?;
+        skip_var_uint(&mut parser, 3)?; // gas_limit
+        if parser.load_bit()? {
+            skip_var_uint(&mut parser, 2)?; // gas_credit
+        }
+        parser.load_uint(8)?; // mode
+        let exit_code = parser.load_uint(32)? as u32 as i32;
+        Ok(Some(Self {
+            success,
+            gas_used,
+            exit_code,
+        }))
+    }
+}
+
+/// `VarUInteger n` with `len_bits` = log2(n) bits of length.
+fn load_var_uint(parser: &mut CellParser, len_bits: usize) -> Result<u64, CellError> {
+    let len = parser.load_uint(len_bits)? as usize;
+    parser.load_uint(len * 8)
+}
+
+fn skip_var_uint(parser: &mut CellParser, len_bits: usize) -> Result<(), CellError> {
+    let len = parser.load_uint(len_bits)? as usize;
+    skip_bits(parser, len * 8)
+}
+
+fn skip_bits(parser: &mut CellParser, mut bit_len: usize) -> Result<(), CellError> {
+    while bit_len > 0 {
+        let chunk = bit_len.min(64);
+        parser.load_uint(chunk)?;
+        bit_len -= chunk;
+    }
+    Ok(())
+}
+
+/// grams:Grams other:ExtraCurrencyCollection
+fn skip_currency_collection(parser: &mut CellParser) -> Result<(), CellError> {
+    skip_var_uint(parser, 4)?;
+    if parser.load_bit()? {
+        parser.next_reference()?;
+    }
+    Ok(())
+}
+
+/// storage_fees_collected:Grams storage_fees_due:(Maybe Grams) status_change:AccStatusChange
+fn skip_storage_phase(parser: &mut CellParser) -> Result<(), CellError> {
+    skip_var_uint(parser, 4)?;
+    if parser.load_bit()? {
+        skip_var_uint(parser, 4)?;
+    }
+    // acst_unchanged$0 | acst_frozen$10 | acst_deleted$11
+    if parser.load_bit()? {
+        parser.load_bit()?;
+    }
+    Ok(())
+}
+
+/// Reads `wc:hex` address of `addr_std` account from ShardAccount BoC.
+/// Returns `None` for empty accounts and other address kinds.
+fn shard_account_address(shard_account_boc: &str) -> Option<String> {
+    let read = || -> Result<Option<String>, CellError> {
+        let shard_account = Cell::from_boc_b64(shard_account_boc)?;
+        let account = shard_account.parser().next_reference()?;
+        let mut parser = account.parser();
+        // account_none$0 | account$1
+        if !parser.load_bit()? {
+            return Ok(None);
+        }
+        // addr_std$10 without anycast
+        if parser.load_uint(2)? != 0b10 || parser.load_bit()? {
+            return Ok(None);
+        }
+        let workchain = parser.load_uint(8)? as u8 as i8;
+        let mut address = String::with_capacity(64);
+        for _ in 0..32 {
+            address.push_str(&format!("{:02x}", parser.load_uint(8)?));
+        }
+        Ok(Some(format!("{workchain}:{address}")))
+    };
+    read().ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::cell::CellBuilder;
+
+    #[test]
+    fn it_reads_shard_account_address() {
+        let account = CellBuilder::new()
+            .store_bit(true)
+            .unwrap()
+            .store_uint(2, 0b10)
+            .unwrap()
+            .store_bit(false)
+            .unwrap()
+            .store_uint(8, 0xff)
+            .unwrap()
+            .store_bytes(&[0xab; 32])
+            .unwrap()
+            .build()
+            .unwrap();
+        let shard_account = CellBuilder::new()
+            .store_reference(Arc::new(account))
+            .unwrap()
+            .store_bytes(&[0; 32])
+            .unwrap()
+            .store_uint(64, 0)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            shard_account_address(&shard_account.to_boc_b64()),
+            Some(format!("-1:{}", "ab".repeat(32)))
+        );
+        assert_eq!(shard_account_address("not a boc"), None);
+    }
+
+    #[test]
+    fn it_decodes_compute_phase() {
+        let transaction = crate::fixtures::read_fixture("transactions/mainnet_ordinary.boc.b64");
+        assert_eq!(
+            ComputePhase::from_transaction_boc(&transaction),
+            Ok(Some(ComputePhase {
+                success: true,
+                gas_used: 10723,
+                exit_code: 0,
+            }))
+        );
+        let stack = crate::fixtures::empty_stack();
+        assert!(ComputePhase::from_transaction_boc(&stack).is_err());
+    }
+
+    #[test]
+    fn it_moves_emulators_between_threads() {
+        let config = crate::fixtures::mainnet_config();
+        let threads: Vec<_> = (0..8)
+            .map(|i| {
+                let mut emulator = TransactionEmulator::new(&config, 0).unwrap();
+                std::thread::spawn(move || {
+                    for lt in 0..50 {
+                        emulator.set_unixtime(1_700_000_000 + i).unwrap();
+                        emulator.set_lt(lt).unwrap();
+                    }
+                    emulator
+                })
+            })
+            .collect();
+        for thread in threads {
+            // dropped on the main thread
+            drop(thread.join().unwrap());
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+
+use crate::error::{check_response, TonlibSysError};
+use crate::instrumentation::{observe, CallInfo};
+use crate::tvm_emulator::*;
+
+/// Owned TVM emulator handle. Destroyed on drop.
+///
+/// Native emulator has no thread affinity but isn't synchronized either,
+/// so the handle may be moved to another thread (`Send`) but not shared (`!Sync`).
+pub struct TvmEmulator {
+    ptr: *mut c_void,
+    address: Option<String>,
+    debug_enabled: bool,
+}
+
+unsafe impl Send for TvmEmulator {}
+
+impl TvmEmulator {
+    pub fn new(
+        code_boc: &str,
+        data_boc: &str,
+        vm_log_verbosity: u32,
+    ) -> Result<Self, TonlibSysError> {
+        let code = c_string(code_boc, "code_boc")?;
+        let data = c_string(data_boc, "data_boc")?;
+        let ptr = unsafe { tvm_emulator_create(code.as_ptr(), data.as_ptr(), vm_log_verbosity) };
+        if ptr.is_null() {
+            return Err(TonlibSysError::NullHandle {
+                function: "tvm_emulator_create",
+            });
+        }
+        Ok(Self {
+            ptr,
+            address: None,
+            debug_enabled: false,
+        })
+    }
+
+    pub fn set_libraries(&mut self, libs_boc: &str) -> Result<(), TonlibSysError> {
+        let libs = c_string(libs_boc, "libs_boc")?;
+        let result = unsafe { tvm_emulator_set_libraries(self.ptr, libs.as_ptr()) };
+        check_setter(result, "tvm_emulator_set_libraries")
+    }
+
+    pub fn set_c7(
+        &mut self,
+        address: &str,
+        unixtime: u32,
+        balance: u64,
+        rand_seed_hex: &str,
+        config_boc: &str,
+    ) -> Result<(), TonlibSysError> {
+        let address_str = c_string(address, "address")?;
+        let rand_seed = c_string(rand_seed_hex, "rand_seed_hex")?;
+        let config = c_string(config_boc, "config_boc")?;
+        let result = unsafe {
+            tvm_emulator_set_c7(
+                self.ptr,
+                address_str.as_ptr(),
+                unixtime,
+                balance,
+                rand_seed.as_ptr(),
+                config.as_ptr(),
+            )
+        };
+        check_setter(result, "tvm_emulator_set_c7")?;
+        self.address = Some(address.to_string());
+        Ok(())
+    }
+
+    pub fn set_gas_limit(&mut self, gas_limit: u64) -> Result<(), TonlibSysError> {
+        let result = unsafe { tvm_emulator_set_gas_limit(self.ptr, gas_limit) };
+        check_setter(result, "tvm_emulator_set_gas_limit")
+    }
+
+    pub fn set_debug_enabled(&mut self, debug_enabled: bool) -> Result<(), TonlibSysError> {
+        let result = unsafe { tvm_emulator_set_debug_enabled(self.ptr, debug_enabled as i32) };
+        check_setter(result, "tvm_emulator_set_debug_enabled")?;
+        self.debug_enabled = debug_enabled;
+        Ok(())
+    }
+
+    pub fn debug_enabled(&self) -> bool {
+        self.debug_enabled
+    }
+
+    pub fn set_prev_blocks_info(&mut self, info_boc: &str) -> Result<(), TonlibSysError> {
+        let info = c_string(info_boc, "info_boc")?;
+        let result = unsafe { tvm_emulator_set_prev_blocks_info(self.ptr, info.as_ptr()) };
+        check_setter(result, "tvm_emulator_set_prev_blocks_info")
+    }
+
+    /// Returns JSON response as described in [`tvm_emulator_run_get_method`].
+    ///
+    /// Fails with [`TonlibSysError::VmError`] if exit code is not 0 or 1,
+    /// unless the response reports `missing_library`. The error keeps the whole response,
+    /// so gas and stack of a failed run are available through [`TonlibSysError::response`].
+    pub fn run_get_method(
+        &mut self,
+        method_id: i32,
+        stack_boc: &str,
+    ) -> Result<String, TonlibSysError> {
+        let stack = c_string(stack_boc, "stack_boc")?;
+        let info = CallInfo {
+            operation: "run_get_method",
+            method_id: Some(method_id),
+            address: &|| self.address.clone(),
+        };
+        observe(info, || {
+            let response = unsafe {
+                take_response(
+                    tvm_emulator_run_get_method(self.ptr, method_id, stack.as_ptr()),
+                    "tvm_emulator_run_get_method",
+                )?
+            };
+            let json = check_response(&response)?;
+            let exit_code = json["vm_exit_code"].as_i64().unwrap_or_default() as i32;
+            if exit_code != 0 && exit_code != 1 && json["missing_library"].is_null() {
+                return Err(TonlibSysError::VmError {
+                    exit_code,
+                    response,
+                });
+            }
+            Ok(response)
+        })
+    }
+
+    /// Returns JSON response as described in [`tvm_emulator_send_external_message`].
+    ///
+    /// Fails with [`TonlibSysError::ExternalNotAccepted`] if the message is not accepted,
+    /// unless the response reports `missing_library`.
+    pub fn send_external_message(
+        &mut self,
+        message_body_boc: &str,
+    ) -> Result<String, TonlibSysError> {
+        let body = c_string(message_body_boc, "message_body_boc")?;
+        let info = CallInfo {
+            operation: "send_external_message",
+            method_id: None,
+            address: &|| self.address.clone(),
+        };
+        observe(info, || {
+            let response = unsafe {
+                take_response(
+                    tvm_emulator_send_external_message(self.ptr, body.as_ptr()),
+                    "tvm_emulator_send_external_message",
+                )?
+            };
+            let json = check_response(&response)?;
+            if json["accepted"].as_bool() == Some(false) && json["missing_library"].is_null() {
+                return Err(TonlibSysError::ExternalNotAccepted {
+                    exit_code: json["vm_exit_code"].as_i64().map(|code| code as i32),
+                    response,
+                });
+            }
+            Ok(response)
+        })
+    }
+
+    /// Returns JSON response as described in [`tvm_emulator_send_internal_message`].
+    pub fn send_internal_message(
+        &mut self,
+        message_body_boc: &str,
+        amount: u64,
+    ) -> Result<String, TonlibSysError> {
+        let body = c_string(message_body_boc, "message_body_boc")?;
+        let info = CallInfo {
+            operation: "send_internal_message",
+            method_id: None,
+            address: &|| self.address.clone(),
+        };
+        observe(info, || {
+            let response = unsafe {
+                take_response(
+                    tvm_emulator_send_internal_message(self.ptr, body.as_ptr(), amount),
+                    "tvm_emulator_send_internal_message",
+                )?
+            };
+            check_response(&response)?;
+            Ok(response)
+        })
+    }
+}
+
+impl Drop for TvmEmulator {
+    fn drop(&mut self) {
+        unsafe { tvm_emulator_destroy(self.ptr) }
+    }
+}
+
+pub(crate) fn c_string(value: &str, argument: &'static str) -> Result<CString, TonlibSysError> {
+    CString::new(value).map_err(|_| TonlibSysError::InteriorNul { argument })
+}
+
+pub(crate) fn check_setter(result: bool, setter: &'static str) -> Result<(), TonlibSysError> {
+    if result {
+        Ok(())
+    } else {
+        Err(TonlibSysError::SetterRejected { setter })
+    }
+}
+
+/// Copies emulator response and frees the original, which is allocated by `strdup`.
+pub(crate) unsafe fn take_response(
+    response: *const c_char,
+    function: &'static str,
+) -> Result<String, TonlibSysError> {
+    if response.is_null() {
+        return Err(TonlibSysError::NullResponse { function });
+    }
+    let result = CStr::from_ptr(response).to_string_lossy().into_owned();
+    libc::free(response as *mut c_void);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_interior_nul() {
+        assert_eq!(
+            TvmEmulator::new("code\0", "data", 0).err(),
+            Some(TonlibSysError::InteriorNul {
+                argument: "code_boc"
+            })
+        );
+    }
+
+    #[test]
+    fn it_keeps_response_of_failed_run() {
+        let contract = crate::fixtures::contract("wallet_v3r2");
+        let mut emulator = TvmEmulator::new(&contract.code, &contract.data, 0).unwrap();
+        let error = emulator
+            .run_get_method(
+                crate::fixtures::method_id("no_such_method"),
+                &crate::fixtures::empty_stack(),
+            )
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            TonlibSysError::VmError { exit_code: 11, .. }
+        ));
+        let response: serde_json::Value = serde_json::from_str(error.response().unwrap()).unwrap();
+        assert_eq!(response["vm_exit_code"], 11);
+        assert!(response["gas_used"].is_string());
+        assert!(response["stack"].is_string());
+    }
+
+    #[test]
+    fn it_runs_emulators_concurrently() {
+        let contract = crate::fixtures::contract("wallet_v3r2");
+        let stack = crate::fixtures::empty_stack();
+        let seqno = crate::fixtures::method_id("seqno");
+        let threads: Vec<_> = (0..16)
+            .map(|_| {
+                let mut emulator = TvmEmulator::new(&contract.code, &contract.data, 0).unwrap();
+                let code = contract.code.clone();
+                let data = contract.data.clone();
+                let stack = stack.clone();
+                std::thread::spawn(move || {
+                    // handle created on the main thread
+                    emulator.run_get_method(seqno, &stack).unwrap();
+                    for _ in 0..50 {
+                        let mut emulator = TvmEmulator::new(&code, &data, 0).unwrap();
+                        emulator.set_gas_limit(1_000_000).unwrap();
+                        emulator.run_get_method(seqno, &stack).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
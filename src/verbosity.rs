@@ -0,0 +1,128 @@
+use std::sync::Mutex;
+
+use crate::error::TonlibSysError;
+#[cfg(not(target_os = "emscripten"))]
+use crate::tonlibjson::tonlib_client_set_verbosity_level;
+use crate::tvm_emulator::emulator_set_verbosity_level;
+
+/// Verbosity level shared by tonlib and emulator logging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Fatal = 0,
+    Error = 1,
+    Warning = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl Verbosity {
+    pub fn level(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Process-wide verbosity. Overrides form a stack, the latest alive one wins.
+struct State {
+    base: Verbosity,
+    overrides: Vec<(u64, Verbosity)>,
+    next_id: u64,
+}
+
+impl State {
+    fn effective(&self) -> Verbosity {
+        self.overrides
+            .last()
+            .map_or(self.base, |(_, verbosity)| *verbosity)
+    }
+}
+
+// Native libraries don't expose the current level, so the base is assumed to be
+// `Error` until `set_verbosity` is called.
+static STATE: Mutex<State> = Mutex::new(State {
+    base: Verbosity::Error,
+    overrides: vec![],
+    next_id: 0,
+});
+
+fn apply(verbosity: Verbosity) -> Result<(), TonlibSysError> {
+    // tonlib is not built for emscripten
+    #[cfg(not(target_os = "emscripten"))]
+    unsafe {
+        tonlib_client_set_verbosity_level(verbosity.level())
+    };
+    if unsafe { emulator_set_verbosity_level(verbosity.level()) } {
+        Ok(())
+    } else {
+        Err(TonlibSysError::SetterRejected {
+            setter: "emulator_set_verbosity_level",
+        })
+    }
+}
+
+/// Sets verbosity of both tonlib and emulator.
+///
+/// Active [`VerbosityGuard`]s take precedence, the level is applied once all of them are dropped.
+/// Calling the raw `*_set_verbosity_level` functions directly bypasses this bookkeeping.
+pub fn set_verbosity(verbosity: Verbosity) -> Result<(), TonlibSysError> {
+    let mut state = STATE.lock().unwrap();
+    state.base = verbosity;
+    if state.overrides.is_empty() {
+        apply(verbosity)?;
+    }
+    Ok(())
+}
+
+/// Currently applied verbosity.
+pub fn verbosity() -> Verbosity {
+    STATE.lock().unwrap().effective()
+}
+
+/// Temporarily overrides verbosity until the guard is dropped.
+pub fn override_verbosity(verbosity: Verbosity) -> Result<VerbosityGuard, TonlibSysError> {
+    let mut state = STATE.lock().unwrap();
+    apply(verbosity)?;
+    let id = state.next_id;
+    state.next_id += 1;
+    state.overrides.push((id, verbosity));
+    Ok(VerbosityGuard { id })
+}
+
+/// Restores previous verbosity on drop. Guards may be dropped in any order.
+#[must_use = "verbosity is restored when the guard is dropped"]
+pub struct VerbosityGuard {
+    id: u64,
+}
+
+impl Drop for VerbosityGuard {
+    fn drop(&mut self) {
+        let mut state = STATE.lock().unwrap();
+        let before = state.effective();
+        state.overrides.retain(|(id, _)| *id != self.id);
+        let after = state.effective();
+        if after != before {
+            let _ = apply(after);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_restores_overridden_verbosity() {
+        set_verbosity(Verbosity::Warning).unwrap();
+        let debug = override_verbosity(Verbosity::Debug).unwrap();
+        let trace = override_verbosity(Verbosity::Trace).unwrap();
+        assert_eq!(verbosity(), Verbosity::Trace);
+
+        set_verbosity(Verbosity::Info).unwrap();
+        assert_eq!(verbosity(), Verbosity::Trace);
+
+        drop(debug);
+        assert_eq!(verbosity(), Verbosity::Trace);
+        drop(trace);
+        assert_eq!(verbosity(), Verbosity::Info);
+    }
+}
@@ -0,0 +1,23 @@
+use std::ffi::{CStr, CString};
+
+use tonlib_sys::{
+    emulator_set_verbosity_level, tonlib_client_json_create, tonlib_client_json_destroy,
+    tonlib_client_json_execute,
+};
+
+fn main() {
+    let request = CString::new(r#"{"@type":"getLogVerbosityLevel"}"#).unwrap();
+    let response = unsafe {
+        let client = tonlib_client_json_create();
+        let response = tonlib_client_json_execute(client, request.as_ptr());
+        let response = CStr::from_ptr(response).to_string_lossy().into_owned();
+        tonlib_client_json_destroy(client);
+        response
+    };
+    assert!(
+        response.contains("logVerbosityLevel"),
+        "unexpected response: {response}"
+    );
+    assert!(unsafe { emulator_set_verbosity_level(0) });
+    println!("tonlib and emulator linked: {response}");
+}